@@ -156,6 +156,8 @@ const SELECTED_CLASSES: &[&str] = &[
     "PhysicsBody2D",
     "PrimitiveMesh",
     "RefCounted",
+    "RegEx",
+    "RegExMatch",
     "RenderingServer",
     "Resource",
     "ResourceFormatLoader",
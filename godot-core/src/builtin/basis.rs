@@ -198,7 +198,11 @@ impl Basis {
 
     /// Returns the scale of the matrix.
     ///
+    /// This is the length of each column. If the basis mirrors (i.e. its [determinant][Self::determinant] is negative), all three
+    /// components are negated, since the mirrored axis cannot be told apart.
+    ///
     /// _Godot equivalent: `Basis.get_scale()`_
+    #[doc(alias = "get_scale")]
     #[must_use]
     pub fn scale(&self) -> Vector3 {
         let det = self.determinant();
@@ -342,6 +346,9 @@ impl Basis {
 
     /// Returns the determinant of the matrix.
     ///
+    /// This is the factor by which the basis scales volumes. It is negative if the basis mirrors, and zero if it collapses space
+    /// onto a plane, line or point.
+    ///
     /// _Godot equivalent: `Basis.determinant()`_
     pub fn determinant(&self) -> real {
         self.to_glam().determinant()
@@ -840,6 +847,52 @@ mod test {
         );
     }
 
+    #[test]
+    fn determinant_scaled() {
+        assert_eq_approx!(
+            Basis::from_scale(Vector3::new(2.0, 3.0, 4.0)).determinant(),
+            24.0
+        );
+        assert_eq_approx!(Basis::FLIP_X.determinant(), -1.0);
+
+        // Rotation does not change the volume.
+        let rotation = Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7);
+        let basis = rotation * Basis::from_scale(Vector3::new(2.0, 3.0, 4.0));
+        assert_eq_approx!(rotation.determinant(), 1.0);
+        assert_eq_approx!(basis.determinant(), 24.0);
+    }
+
+    #[test]
+    fn orthonormalized_skewed() {
+        let skewed = Basis::from_cols(
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.5, 3.0),
+        );
+
+        let basis = skewed.orthonormalized();
+        assert_eq_approx!(basis, Basis::IDENTITY);
+
+        let rotation = Basis::from_axis_angle(Vector3::UP, 0.5);
+        let basis = (rotation * skewed).orthonormalized();
+        assert_eq_approx!(basis.transposed() * basis, Basis::IDENTITY);
+        assert_eq_approx!(basis.determinant(), 1.0);
+        assert_eq_approx!(basis, rotation);
+    }
+
+    #[test]
+    fn scale_extraction() {
+        let scale = Vector3::new(2.0, 3.0, 4.0);
+        assert_eq_approx!(Basis::from_scale(scale).scale(), scale);
+
+        let rotation = Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7);
+        assert_eq_approx!((rotation * Basis::from_scale(scale)).scale(), scale);
+
+        // Mirroring negates all components.
+        let mirrored = Basis::from_scale(Vector3::new(-2.0, 3.0, 4.0));
+        assert_eq_approx!(mirrored.scale(), Vector3::new(-2.0, -3.0, -4.0));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
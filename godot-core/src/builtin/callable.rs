@@ -177,6 +177,17 @@ impl Callable {
         self.as_inner().callv(arguments)
     }
 
+    /// Schedules a call of the method represented by this callable, to be executed at idle time of the current frame.
+    ///
+    /// Unlike [`callv`][Self::callv], the method is not invoked immediately, which makes this safe to use for modifying the scene tree
+    /// from within signal handlers or physics callbacks. Since the call happens later, its return value is not available.
+    ///
+    /// _Godot equivalent: `call_deferred`_
+    pub fn call_deferred(&self, arguments: &[Variant]) {
+        // Builtin vararg methods are not yet generated in `InnerCallable`, see https://github.com/godot-rust/gdext/issues/382.
+        self.to_variant().call("call_deferred", arguments);
+    }
+
     /// Returns a copy of this Callable with one or more arguments bound, reading them from an array.
     ///
     /// _Godot equivalent: `bindv`_
@@ -206,7 +217,8 @@ impl Callable {
     /// Returns the object on which this callable is called.
     ///
     /// Returns `None` when this callable doesn't have any target object to call a method on, regardless of
-    /// if the method exists for that target or not.
+    /// if the method exists for that target or not. This includes the case where the target object has been freed;
+    /// use [`object_id()`][Self::object_id] to identify the former target.
     ///
     /// _Godot equivalent: `get_object`_
     pub fn object(&self) -> Option<Gd<Object>> {
@@ -264,6 +276,9 @@ impl Callable {
     /// Returns true if the callable's object exists and has a valid method name assigned, or is a custom
     /// callable.
     ///
+    /// Callables do not keep their target object alive, so a callable whose object has been freed is no longer valid. This makes
+    /// `is_valid()` a cheap check before invoking a callable that was stored for later.
+    ///
     /// _Godot equivalent: `is_valid`_
     pub fn is_valid(&self) -> bool {
         self.as_inner().is_valid()
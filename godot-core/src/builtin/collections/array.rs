@@ -359,6 +359,63 @@ impl<T: ArrayElement> Array<T> {
         unsafe { self.as_inner_mut() }.erase(value.to_variant());
     }
 
+    /// Retains only the elements for which `f` returns `true`, removing all others.
+    ///
+    /// Elements are visited exactly once, in order. Their relative order is preserved.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let removed: Vec<usize> = self
+            .iter_shared()
+            .enumerate()
+            .filter_map(|(index, element)| (!f(&element)).then_some(index))
+            .collect();
+
+        // Back-to-front, so that removals don't shift the indices of elements yet to be removed.
+        for index in removed.into_iter().rev() {
+            // SAFETY: We do not write any values to the array, we just remove one.
+            unsafe { self.as_inner_mut() }.remove_at(to_i64(index));
+        }
+    }
+
+    /// ⚠️ Removes the elements in `range` from the array, returning them as an iterator.
+    ///
+    /// Unlike [`Vec::drain()`], the elements are removed eagerly: the array is already modified when this method returns, regardless of
+    /// whether the iterator is consumed.
+    ///
+    /// # Panics
+    /// If the start of the range is greater than its end, or if the end is greater than `len()`.
+    pub fn drain(&mut self, range: impl std::ops::RangeBounds<usize>) -> impl Iterator<Item = T> {
+        use std::ops::Bound;
+
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        assert!(
+            start <= end,
+            "Array drain range starts at {start} but ends at {end}"
+        );
+        assert!(
+            end <= len,
+            "Array drain range end {end} is out of bounds: length is {len}"
+        );
+
+        let drained: Vec<T> = (start..end).map(|index| self.at(index)).collect();
+        let tail = self.subarray_shallow(end, len, None);
+
+        self.shrink(start);
+        self.extend_array(tail);
+
+        drained.into_iter()
+    }
+
     /// Assigns the given value to all elements in the array. This can be used together with
     /// `resize` to create an array with a given size and initialized elements.
     pub fn fill(&mut self, value: &T) {
@@ -584,6 +641,37 @@ impl<T: ArrayElement> Array<T> {
         )
     }
 
+    /// Binary-searches a sorted array with a comparator function, like [`slice::binary_search_by()`].
+    ///
+    /// `f` is called with an element and returns whether it is less than, equal to or greater than the target. Only the visited elements
+    /// are read from the array, so the search performs `O(log n)` element accesses without copying the array.
+    ///
+    /// If a matching element is found, returns `Ok` with its index; if several elements match, any one of them may be returned. Otherwise,
+    /// returns `Err` with the index at which a matching element could be inserted while maintaining sort order.
+    ///
+    /// Calling this on an array that is not sorted according to `f` results in an unspecified (but safe) result.
+    pub fn binary_search_by(
+        &self,
+        mut f: impl FnMut(&T) -> std::cmp::Ordering,
+    ) -> Result<usize, usize> {
+        use std::cmp::Ordering;
+
+        let mut low = 0;
+        let mut high = self.len();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            match f(&self.at(mid)) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
     /// Reverses the order of the elements in the array.
     pub fn reverse(&mut self) {
         // SAFETY: We do not write any values that don't already exist in the array, so all values have the correct type.
@@ -1137,19 +1225,54 @@ impl<T: ArrayElement + ToGodot> FromIterator<T> for Array<T> {
 }
 
 /// Extends a `Array` with the contents of an iterator.
+///
+/// The array is grown once by the lower bound of `iter.size_hint()`, so iterators with an exact size (such as `Vec` or slices) need
+/// a single reallocation.
 impl<T: ArrayElement + ToGodot> Extend<T> for Array<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        // Unfortunately the GDExtension API does not offer the equivalent of `Vec::reserve`.
-        // Otherwise, we could use it to pre-allocate based on `iter.size_hint()`.
-        //
-        // A faster implementation using `resize()` and direct pointer writes might still be
-        // possible.
-        for item in iter.into_iter() {
-            self.push(item);
+        // The GDExtension API does not offer the equivalent of `Vec::reserve`. Instead, resize to the expected length up front and
+        // overwrite the new slots; only elements beyond the size hint are pushed one by one.
+        let iter = iter.into_iter();
+        let start = self.len();
+        let reserved_end = start + iter.size_hint().0;
+
+        // Created before resizing, so that unused slots are removed on every exit path: after an iterator that yields fewer elements
+        // than its size hint promised, but also when `next()` or `to_variant()` panics and the panic is caught further up.
+        let mut guard = ShrinkOnDrop {
+            array: self,
+            len: start,
+        };
+
+        if reserved_end > start {
+            // SAFETY: The new slots hold `Variant::nil()` (or the type's default), which may not be a valid `T`. Only the slots below
+            // `guard.len` are filled with values of type `T`; the guard shrinks the array to `guard.len` when dropped, including during
+            // unwinding, so no placeholder is observable once `extend()` returns or panics.
+            unsafe { guard.array.as_inner_mut() }.resize(to_i64(reserved_end));
+        }
+
+        for item in iter {
+            if guard.len < reserved_end {
+                guard.array.set(guard.len, item);
+            } else {
+                guard.array.push(item);
+            }
+            guard.len += 1;
         }
     }
 }
 
+/// Shrinks an array to `len` when dropped; see `Extend` impl of `Array`.
+struct ShrinkOnDrop<'a, T: ArrayElement> {
+    array: &'a mut Array<T>,
+    len: usize,
+}
+
+impl<T: ArrayElement> Drop for ShrinkOnDrop<'_, T> {
+    fn drop(&mut self) {
+        self.array.shrink(self.len);
+    }
+}
+
 /// Converts this array to a strongly typed Rust vector.
 impl<T: ArrayElement + FromGodot> From<&Array<T>> for Vec<T> {
     fn from(array: &Array<T>) -> Vec<T> {
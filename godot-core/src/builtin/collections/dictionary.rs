@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Variant, VariantArray};
+use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, ToGodot};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -125,6 +126,23 @@ impl Dictionary {
         }
     }
 
+    /// Returns the value for the given key converted to `T`, or `None` if the key is absent.
+    ///
+    /// The outer `Option` tells whether the key is present, while the inner `Result` tells whether its value could be converted to
+    /// `T`. This is [`get()`][Self::get] followed by [`Variant::try_to()`].
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let config = dict! { "volume": 0.8, "name": "Player" };
+    ///
+    /// assert_eq!(config.get_as::<_, f64>("volume").unwrap().ok(), Some(0.8));
+    /// assert!(config.get_as::<_, f64>("name").unwrap().is_err());
+    /// assert!(config.get_as::<_, f64>("missing").is_none());
+    /// ```
+    pub fn get_as<K: ToGodot, T: FromGodot>(&self, key: K) -> Option<Result<T, ConvertError>> {
+        self.get(key).map(|value| value.try_to::<T>())
+    }
+
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
     ///
     /// This method does not let you differentiate `NIL` values stored as values from absent keys.
@@ -260,6 +278,18 @@ impl Dictionary {
         self.as_inner().merge(other, overwrite)
     }
 
+    /// Returns a shallow copy of `self` with all keys and values from `other` copied into it. `self` is not modified.
+    ///
+    /// If `overwrite` is true, values from `other` replace those of pre-existing keys; otherwise, existing values are kept.
+    /// See [`extend_dictionary()`][Self::extend_dictionary] for the in-place version.
+    ///
+    /// _Godot equivalent: `merged`_
+    pub fn merged(&self, other: &Self, overwrite: bool) -> Self {
+        let mut result = self.duplicate_shallow();
+        result.extend_dictionary(other.clone(), overwrite);
+        result
+    }
+
     /// Deep copy, duplicating nested collections.
     ///
     /// All nested arrays and dictionaries are duplicated and will not be shared with the original dictionary.
@@ -299,6 +329,20 @@ impl Dictionary {
         Iter::new(self)
     }
 
+    /// Returns an iterator over the key-value pairs of the `Dictionary`, based on a snapshot of its keys.
+    ///
+    /// Unlike [`iter_shared()`][Self::iter_shared], the list of keys is copied up front, so modifying the `Dictionary` through another
+    /// reference does not invalidate the iteration order. Keys which are removed in the meantime are skipped; keys which are added are
+    /// not visited. Values are looked up lazily, so they reflect the state at the time each pair is yielded.
+    ///
+    /// Use `iter_shared()` if you don't need this guarantee, as it avoids copying the keys.
+    pub fn iter(&self) -> impl Iterator<Item = (Variant, Variant)> + '_ {
+        let keys: Vec<Variant> = self.keys_array().iter_shared().collect();
+
+        keys.into_iter()
+            .filter_map(move |key| self.get(key.clone()).map(|value| (key, value)))
+    }
+
     /// Returns an iterator over the keys in a `Dictionary`.
     ///
     /// The keys are each of type `Variant`. Each key references the original `Dictionary`, but instead of a `&`-reference to keys pairs
@@ -794,3 +794,25 @@ impl_packed_array!(
         PartialEq => packed_color_array_operator_equal;
     },
 );
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type-specific APIs
+
+impl PackedStringArray {
+    /// Returns an iterator over the elements as Rust `String`s, in order.
+    ///
+    /// Each element is converted when the iterator reaches it. To iterate over the `GString` elements without conversion, use
+    /// `as_slice().iter()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let files = PackedStringArray::from(vec!["a.png".into(), "b.txt".into(), "c.png".into()]);
+    /// let images: Vec<String> = files.iter_str().filter(|file| file.ends_with(".png")).collect();
+    ///
+    /// assert_eq!(images, ["a.png", "c.png"]);
+    /// ```
+    pub fn iter_str(&self) -> impl Iterator<Item = String> + '_ {
+        self.as_slice().iter().map(GString::to_string)
+    }
+}
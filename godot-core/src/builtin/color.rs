@@ -136,6 +136,15 @@ impl Color {
         InnerColor::from_hsv(h, s, v, 1.0)
     }
 
+    /// Constructs a `Color` from an [HSV profile](https://en.wikipedia.org/wiki/HSL_and_HSV) with alpha, using
+    /// [Godot's builtin method](https://docs.godotengine.org/en/stable/classes/class_color.html#class-color-method-from-hsv).
+    ///
+    /// Like [`Color::from_hsv`], but with an explicit alpha value `a`.
+    #[doc(alias = "from_hsv")]
+    pub fn from_hsva(h: f64, s: f64, v: f64, a: f64) -> Self {
+        InnerColor::from_hsv(h, s, v, a)
+    }
+
     /// Constructs a `Color` from an [OK HSL
     /// profile](https://bottosson.github.io/posts/colorpicker/). The hue (`h`), saturation (`s`),
     /// and lightness (`l`) are typically between 0.0 and 1.0. Alpha is set to 1; use
@@ -203,6 +212,29 @@ impl Color {
         self.a = from_u8(a);
     }
 
+    /// Returns the HSV hue of this color, in the range from 0 to 1. Grays (including black and white) have a hue of 0.
+    ///
+    /// Unlike [`Color::to_hsv`], this also works for colors outside the range from 0 to 1.
+    ///
+    /// _Godot equivalent: `Color.h`_
+    pub fn h(self) -> f32 {
+        rgba_to_hsva(self.r, self.g, self.b, self.a).0
+    }
+
+    /// Returns the HSV saturation of this color. Grays (including black and white) have a saturation of 0.
+    ///
+    /// _Godot equivalent: `Color.s`_
+    pub fn s(self) -> f32 {
+        rgba_to_hsva(self.r, self.g, self.b, self.a).1
+    }
+
+    /// Returns the HSV value (brightness) of this color, which is the largest of the red, green and blue channels.
+    ///
+    /// _Godot equivalent: `Color.v`_
+    pub fn v(self) -> f32 {
+        self.r.max(self.g).max(self.b)
+    }
+
     /// Returns the light intensity of the color, as a value between 0.0 and 1.0 (inclusive). This
     /// is useful when determining whether a color is light or dark. Colors with a luminance
     /// smaller than 0.5 can be generally considered dark.
@@ -215,6 +247,10 @@ impl Color {
     }
 
     /// Blends the given color on top of this color, taking its alpha into account.
+    ///
+    /// This is the "over" operator of alpha compositing, computed on straight (non-premultiplied) colors: the resulting alpha is
+    /// `over.a + self.a * (1 - over.a)`, and each color channel is the alpha-weighted average of both colors, divided by that alpha.
+    /// If the resulting alpha is 0, the result is transparent black.
     #[must_use]
     pub fn blend(self, over: Color) -> Self {
         self.as_inner().blend(over)
@@ -362,6 +398,8 @@ unsafe impl GodotFfi for Color {
 crate::meta::impl_godot_as_self!(Color);
 
 impl ApproxEq for Color {
+    /// Returns if the two colors are approximately equal, by comparing each channel separately.
+    #[doc(alias = "is_equal_approx")]
     fn approx_eq(&self, other: &Self) -> bool {
         // TODO(bromeon): re-implement in Rust
         self.as_inner().is_equal_approx(*other)
@@ -82,7 +82,14 @@ pub mod iter {
 
 /// Specialized types related to Godot's various string implementations.
 pub mod strings {
-    pub use super::string::TransientStringNameOrd;
+    pub use super::string::{
+        coerce_to_node_path, CaseInsensitiveNodePath, GStringBuilder, HashedNodePath,
+        NodePathComponent, NodePathComponents, NodePathKey, NodePathVisitor, PropertyPath,
+        StrictNodePathBuilder, StringNameMap, TransientStringNameOrd,
+    };
+
+    #[cfg(feature = "serde")]
+    pub use super::string::{deserialize_node_path_seq, serde_empty_as_none};
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -306,6 +306,8 @@ impl Default for Quaternion {
 }
 
 impl ApproxEq for Quaternion {
+    /// Returns if the two quaternions are approximately equal, by comparing each component separately.
+    #[doc(alias = "is_equal_approx")]
     fn approx_eq(&self, other: &Self) -> bool {
         self.x.approx_eq(&other.x)
             && self.y.approx_eq(&other.y)
@@ -451,6 +451,49 @@ mod test {
         assert_eq!(rect, new_rect);
     }
 
+    #[test]
+    fn contains_point_edges() {
+        let rect = Rect2i::from_components(2, 3, 4, 5);
+
+        // Left and top edges are inclusive, including the corner.
+        assert!(rect.contains_point(Vector2i::new(2, 3)));
+        assert!(rect.contains_point(Vector2i::new(2, 7)));
+        assert!(rect.contains_point(Vector2i::new(5, 3)));
+
+        // Right and bottom edges are exclusive.
+        assert!(!rect.contains_point(Vector2i::new(6, 3)));
+        assert!(!rect.contains_point(Vector2i::new(2, 8)));
+        assert!(!rect.contains_point(Vector2i::new(6, 8)));
+        assert!(rect.contains_point(Vector2i::new(5, 7)));
+
+        // Just outside left/top.
+        assert!(!rect.contains_point(Vector2i::new(1, 3)));
+        assert!(!rect.contains_point(Vector2i::new(2, 2)));
+    }
+
+    #[test]
+    fn intersects_touching_edges() {
+        let a = Rect2i::from_components(0, 0, 4, 4);
+        let right = Rect2i::from_components(4, 0, 4, 4);
+        let below = Rect2i::from_components(0, 4, 4, 4);
+        let overlapping = Rect2i::from_components(3, 3, 4, 4);
+
+        // Sharing an edge is not an intersection, as no cell is covered by both.
+        assert!(!a.intersects(right));
+        assert!(!a.intersects(below));
+        assert!(a.intersects(overlapping));
+        assert_eq!(
+            a.intersection(overlapping),
+            Some(Rect2i::from_components(3, 3, 1, 1))
+        );
+
+        // Merging disjoint rects covers the gap between them.
+        assert_eq!(
+            Rect2i::from_components(0, 0, 2, 2).merge(Rect2i::from_components(5, 6, 1, 1)),
+            Rect2i::from_components(0, 0, 6, 7)
+        );
+    }
+
     #[test]
     #[should_panic]
     fn expand_self_negative_panics() {
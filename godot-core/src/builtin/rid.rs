@@ -66,6 +66,14 @@ impl Rid {
         }
     }
 
+    /// Returns the invalid RID, which never refers to a resource.
+    ///
+    /// Equivalent to [`Rid::Invalid`] and to `Rid::new(0)`.
+    #[inline]
+    pub const fn invalid() -> Self {
+        Self::Invalid
+    }
+
     /// Convert this RID into a [`u64`]. Returns 0 if it is invalid.
     ///
     /// _Godot equivalent: `Rid.get_id()`_
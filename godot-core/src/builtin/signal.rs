@@ -11,11 +11,12 @@ use std::ptr;
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
+use crate::classes::object::ConnectFlags;
 use crate::classes::Object;
 use crate::global::Error;
-use crate::meta::{FromGodot, GodotType, ToGodot};
+use crate::meta::{FromGodot, GodotType, SignalArgs, ToGodot};
 use crate::obj::bounds::DynMemory;
-use crate::obj::{Bounds, Gd, GodotClass, InstanceId};
+use crate::obj::{Bounds, EngineBitfield, Gd, GodotClass, InstanceId};
 use sys::{ffi_methods, GodotFfi};
 
 /// A `Signal` represents a signal of an Object instance in Godot.
@@ -80,6 +81,30 @@ impl Signal {
         Error::from_godot(error as i32)
     }
 
+    /// Connects this signal to the specified callable, with typed [`ConnectFlags`].
+    ///
+    /// Flags such as [`ConnectFlags::ONE_SHOT`] or [`ConnectFlags::DEFERRED`] configure the connection's behavior, and can be combined
+    /// with `|`. Use `ConnectFlags::from_ord(0)` for a regular connection.
+    ///
+    /// On success, returns a [`SignalConnection`] handle which remembers the connected callable, and can be used to
+    /// [`disconnect`][SignalConnection::disconnect] later. On failure, returns the error reported by Godot; see [`Self::connect`].
+    pub fn connect_ex(
+        &self,
+        callable: Callable,
+        flags: ConnectFlags,
+    ) -> Result<SignalConnection, Error> {
+        let error = self.connect(callable.clone(), flags.ord() as i64);
+
+        if error == Error::OK {
+            Ok(SignalConnection {
+                signal: self.clone(),
+                callable,
+            })
+        } else {
+            Err(error)
+        }
+    }
+
     /// Disconnects this signal from the specified [`Callable`].
     ///
     /// If the connection does not exist, generates an error. Use [`Self::is_connected`] to make sure that the connection exists.
@@ -98,6 +123,21 @@ impl Signal {
         object.emit_signal(self.name(), varargs);
     }
 
+    /// Emits this signal, converting each element of the tuple `args` to a [`Variant`].
+    ///
+    /// Arguments are passed in tuple order; `()` emits without arguments. This saves building a `&[Variant]` slice by hand:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn emit(signal: Signal) {
+    /// signal.emit_typed((42_i64, GString::from("hello")));
+    /// // Same as:
+    /// signal.emit(&[42_i64.to_variant(), GString::from("hello").to_variant()]);
+    /// # }
+    /// ```
+    pub fn emit_typed<A: SignalArgs>(&self, args: A) {
+        self.emit(&args.to_variants());
+    }
+
     /// Returns an [`Array`] of connections for this signal.
     ///
     /// Each connection is represented as a Dictionary that contains three entries:
@@ -157,6 +197,49 @@ impl Signal {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// SignalConnection
+
+/// Handle to a connection between a [`Signal`] and a [`Callable`].
+///
+/// Returned by [`Signal::connect_ex`]. Dropping the handle does **not** disconnect the signal; call [`disconnect`][Self::disconnect]
+/// explicitly to do that.
+#[derive(Clone, Debug)]
+pub struct SignalConnection {
+    signal: Signal,
+    callable: Callable,
+}
+
+impl SignalConnection {
+    /// The signal that was connected.
+    pub fn signal(&self) -> &Signal {
+        &self.signal
+    }
+
+    /// The callable that was connected to the signal.
+    pub fn callable(&self) -> &Callable {
+        &self.callable
+    }
+
+    /// Returns `true` if the connection still exists.
+    ///
+    /// This is `false` after [`disconnect`][Self::disconnect], or after a [`ONE_SHOT`][ConnectFlags::ONE_SHOT] connection has fired.
+    pub fn is_connected(&self) -> bool {
+        self.signal.is_connected(self.callable.clone())
+    }
+
+    /// Disconnects the callable from the signal.
+    ///
+    /// If the connection no longer exists (e.g. a one-shot connection that already fired), this does nothing.
+    pub fn disconnect(self) {
+        if self.is_connected() {
+            self.signal.disconnect(self.callable);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 // SAFETY:
 // The `opaque` in `Signal` is just a pair of pointers, and requires no special initialization or cleanup
 // beyond what is done in `from_opaque` and `drop`. So using `*mut Opaque` is safe.
@@ -12,7 +12,11 @@ use godot_ffi as sys;
 use sys::types::OpaqueString;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use crate::builtin::{inner, NodePath, StringName};
+use crate::builtin::{inner, NodePath, PackedFloat64Array, PackedStringArray, StringName};
+use crate::classes::{RegEx, RegExMatch};
+use crate::global::Error as GodotError;
+use crate::meta::error::RegexError;
+use crate::obj::{Gd, NewGd};
 
 /// Godot's reference counted string type.
 ///
@@ -49,6 +53,12 @@ use crate::builtin::{inner, NodePath, StringName};
 /// Note that Godot ignores any bytes after a null-byte. This means that for instance `"hello, world!"` and `"hello, world!\0 ignored by Godot"`
 /// will be treated as the same string if converted to a `GString`.
 ///
+/// # Hash maps
+///
+/// `GString` implements `Hash` and `Eq`, so it can be used directly as a key in `HashMap` and `HashSet`, without converting to `String`.
+/// Both are delegated to Godot: the hash is the one returned by [`hash()`][Self::hash], which is the same value Godot computes for a
+/// [`StringName`][crate::builtin::StringName] with equal content.
+///
 /// # All string types
 ///
 /// | Intended use case | String type                                |
@@ -103,6 +113,239 @@ impl GString {
         }
     }
 
+    /// Parses the string as an integer, following Godot's lenient rules.
+    ///
+    /// Non-numeric characters are ignored rather than causing an error, so `"42abc"` yields `42` and `"abc"` yields `0`.
+    /// Use Rust's [`str::parse()`] if you need strict parsing.
+    ///
+    /// _Godot equivalent: `to_int`_
+    pub fn to_int(&self) -> i64 {
+        self.as_inner().to_int()
+    }
+
+    /// Parses the string as a floating-point number, following Godot's lenient rules.
+    ///
+    /// Parsing stops at the first character that cannot be part of a number, so `"-1.5xyz"` yields `-1.5` and `"abc"` yields `0.0`.
+    /// Use Rust's [`str::parse()`] if you need strict parsing.
+    ///
+    /// _Godot equivalent: `to_float`_
+    pub fn to_float(&self) -> f64 {
+        self.as_inner().to_float()
+    }
+
+    /// Splits the string by `delimiter` and parses each part as a float, using the same rules as [`to_float()`][Self::to_float].
+    ///
+    /// Empty parts are kept and parsed as `0.0`, e.g. `"1,,3"` yields `[1.0, 0.0, 3.0]`.
+    ///
+    /// _Godot equivalent: `split_floats`_
+    pub fn split_floats(&self, delimiter: &str) -> PackedFloat64Array {
+        self.as_inner().split_floats(delimiter.into(), true)
+    }
+
+    /// Returns `true` if the string starts with `prefix`. Comparison is case-sensitive.
+    ///
+    /// An empty `prefix` always matches.
+    ///
+    /// _Godot equivalent: `begins_with`_
+    #[doc(alias = "starts_with")]
+    pub fn begins_with(&self, prefix: &str) -> bool {
+        self.as_inner().begins_with(prefix.into())
+    }
+
+    /// Returns `true` if the string ends with `suffix`. Comparison is case-sensitive.
+    ///
+    /// An empty `suffix` always matches.
+    ///
+    /// _Godot equivalent: `ends_with`_
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.as_inner().ends_with(suffix.into())
+    }
+
+    /// Returns `true` if `needle` occurs anywhere in the string. Comparison is case-sensitive.
+    ///
+    /// Unlike Rust's [`str::contains()`], an empty `needle` never matches, since Godot's substring search does not find empty strings.
+    ///
+    /// _Godot equivalent: `contains`_
+    pub fn contains(&self, needle: &str) -> bool {
+        self.as_inner().contains(needle.into())
+    }
+
+    /// Returns the first `n` characters of the string, or all but the last `-n` characters if `n` is negative.
+    ///
+    /// Characters are counted like in [`len()`][Self::len], i.e. as Unicode code points. If `n` exceeds the length, the whole string is
+    /// returned; if `-n` exceeds it, the result is empty. For example, `"Hello"` gives `"He"` for `n = 2` and `"Hel"` for `n = -2`.
+    ///
+    /// _Godot equivalent: `left`_
+    pub fn left(&self, n: i32) -> GString {
+        self.as_inner().left(n.into())
+    }
+
+    /// Returns the last `n` characters of the string, or all but the first `-n` characters if `n` is negative.
+    ///
+    /// Mirrors [`left()`][Self::left]: for `"Hello"`, `n = 2` gives `"lo"` and `n = -2` gives `"llo"`.
+    ///
+    /// _Godot equivalent: `right`_
+    pub fn right(&self, n: i32) -> GString {
+        self.as_inner().right(n.into())
+    }
+
+    /// Returns `len` characters starting at character index `from`, or all characters from `from` to the end if `len` is `-1`.
+    ///
+    /// A range extending past the end of the string is clamped to it. If `from` is negative or not less than the string length, or if
+    /// `len` is 0 or negative other than `-1`, the result is empty.
+    ///
+    /// _Godot equivalent: `substr`_
+    pub fn substr(&self, from: i32, len: i32) -> GString {
+        self.as_inner().substr(from.into(), len.into())
+    }
+
+    /// Concatenates `parts`, using this string as the separator between them.
+    ///
+    /// No separator is added before the first or after the last part, so an empty iterator yields an empty string, and a single part is
+    /// returned unchanged.
+    ///
+    /// _Godot equivalent: `join`_
+    pub fn join<S: AsRef<str>>(&self, parts: impl IntoIterator<Item = S>) -> GString {
+        let parts: PackedStringArray = parts
+            .into_iter()
+            .map(|part| GString::from(part.as_ref()))
+            .collect();
+
+        self.as_inner().join(parts)
+    }
+
+    /// Joins `part` to this string as a file path, inserting a `/` separator only if neither side already provides one.
+    ///
+    /// `"res://assets"` joined with `"icon.png"` or `"/icon.png"` both give `"res://assets/icon.png"`. If this string is empty, `part`
+    /// is returned unchanged. Separators are not collapsed beyond that, so if both sides have a `/`, the result contains `//`.
+    ///
+    /// This works on filesystem and resource paths; to combine scene-tree paths, use [`NodePath`] methods instead.
+    ///
+    /// _Godot equivalent: `path_join`_
+    #[doc(alias = "plus_file")]
+    pub fn path_join(&self, part: &str) -> GString {
+        self.as_inner().path_join(part.into())
+    }
+
+    /// Returns the file extension of this path, i.e. everything after the last `.` in the file name, or an empty string if there is none.
+    ///
+    /// Only the file name is considered, so dots in directory names are ignored: `"res://v1.2/readme"` has no extension. For
+    /// `"icon.tar.gz"`, the extension is `"gz"`.
+    ///
+    /// _Godot equivalent: `get_extension`_
+    pub fn get_extension(&self) -> GString {
+        self.as_inner().get_extension()
+    }
+
+    /// Returns this path without its file extension and the `.` before it; see [`get_extension()`][Self::get_extension].
+    ///
+    /// The directory part is kept, so `"res://textures/icon.tar.gz"` becomes `"res://textures/icon.tar"`. A path without extension is
+    /// returned unchanged.
+    ///
+    /// _Godot equivalent: `get_basename`_
+    pub fn get_basename(&self) -> GString {
+        self.as_inner().get_basename()
+    }
+
+    /// Returns how similar this string is to `other`, from `0.0` (nothing in common) to `1.0` (equal).
+    ///
+    /// The score is the Sørensen–Dice coefficient over character pairs (bigrams): twice the number of shared bigrams, divided by the
+    /// total number of bigrams in both strings. Comparison is case-sensitive. Strings shorter than two characters have no bigrams and
+    /// thus score `0.0` unless equal. This is the same metric as used by the Godot editor's fuzzy search.
+    ///
+    /// _Godot equivalent: `similarity`_
+    pub fn similarity(&self, other: &str) -> f64 {
+        self.as_inner().similarity(other.into())
+    }
+
+    /// Converts the string to `snake_case`, following Godot's rules for word boundaries.
+    ///
+    /// Consecutive capitals are treated as one word, so `"MyHTTPServer"` becomes `"my_http_server"`.
+    ///
+    /// _Godot equivalent: `to_snake_case`_
+    pub fn to_snake_case(&self) -> GString {
+        self.as_inner().to_snake_case()
+    }
+
+    /// Converts the string to `PascalCase`, following Godot's rules for word boundaries.
+    ///
+    /// Each word is capitalized and the rest lowercased, so `"my_http_server"` becomes `"MyHttpServer"`.
+    ///
+    /// _Godot equivalent: `to_pascal_case`_
+    pub fn to_pascal_case(&self) -> GString {
+        self.as_inner().to_pascal_case()
+    }
+
+    /// Converts the string to `camelCase`, following Godot's rules for word boundaries.
+    ///
+    /// Like [`to_pascal_case()`][Self::to_pascal_case], but the first letter is lowercase, so `"my_http_server"` becomes
+    /// `"myHttpServer"`.
+    ///
+    /// _Godot equivalent: `to_camel_case`_
+    pub fn to_camel_case(&self) -> GString {
+        self.as_inner().to_camel_case()
+    }
+
+    /// Escapes the characters `&`, `<` and `>` as XML entities, and with `escape_quotes` also `"` and `'`.
+    ///
+    /// For example, `<b>&` becomes `&lt;b&gt;&amp;`. Use this when embedding text in XML or HTML snippets; quotes only need escaping
+    /// inside attribute values.
+    ///
+    /// _Godot equivalent: `xml_escape`_
+    pub fn xml_escape(&self, escape_quotes: bool) -> GString {
+        self.as_inner().xml_escape(escape_quotes)
+    }
+
+    /// Replaces the XML entities `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;` with the characters they stand for.
+    ///
+    /// This reverses [`xml_escape()`][Self::xml_escape].
+    ///
+    /// _Godot equivalent: `xml_unescape`_
+    pub fn xml_unescape(&self) -> GString {
+        self.as_inner().xml_unescape()
+    }
+
+    /// Percent-encodes the string for use in a URL, e.g. as a query parameter.
+    ///
+    /// ASCII letters, digits and `-`, `_`, `.`, `~` are kept; all other characters are encoded as the `%XX` hex codes of their UTF-8
+    /// bytes. For example, `"Godot Engine: 'docs'"` becomes `"Godot%20Engine%3A%20%27docs%27"`. This matches the encoding that
+    /// Godot's `HTTPClient` expects.
+    ///
+    /// _Godot equivalent: `uri_encode`_
+    pub fn uri_encode(&self) -> GString {
+        self.as_inner().uri_encode()
+    }
+
+    /// Decodes a percent-encoded URL string, reversing [`uri_encode()`][Self::uri_encode].
+    ///
+    /// _Godot equivalent: `uri_decode`_
+    pub fn uri_decode(&self) -> GString {
+        self.as_inner().uri_decode()
+    }
+
+    /// Searches the string for the first match of the regular expression `pattern`, using Godot's `RegEx` engine.
+    ///
+    /// Returns `Ok(None)` if the pattern compiles but does not match. Captured groups, including named ones, are available through
+    /// [`RegExMatch::get_string()`][crate::classes::RegExMatch::get_string] and
+    /// [`RegExMatch::get_names()`][crate::classes::RegExMatch::get_names].
+    ///
+    /// The pattern is compiled on every call; keep a [`RegEx`] instance around if you search repeatedly with the same pattern.
+    ///
+    /// # Errors
+    /// Returns [`RegexError`] if `pattern` is not a valid regular expression. Godot also prints an error in that case.
+    ///
+    /// _Godot equivalent: `RegEx.create_from_string(pattern).search(self)`_
+    pub fn regex_match(&self, pattern: &str) -> Result<Option<Gd<RegExMatch>>, RegexError> {
+        let mut regex = RegEx::new_gd();
+
+        let error = regex.compile(pattern.into());
+        if error != GodotError::OK {
+            return Err(RegexError::new(pattern.to_string(), error));
+        }
+
+        Ok(regex.search(self.clone()))
+    }
+
     ffi_methods! {
         type sys::GDExtensionStringPtr = *mut Self;
 
@@ -242,6 +485,10 @@ impl From<&[char]> for GString {
 }
 
 impl From<String> for GString {
+    /// Converts this `String` to a `GString`.
+    ///
+    /// Godot stores strings as UTF-32, so the UTF-8 buffer of `value` cannot be moved into Godot. This is identical to
+    /// `GString::from(value.as_str())`, and as such there is no performance benefit.
     fn from(value: String) -> Self {
         value.as_str().into()
     }
@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use super::GString;
+
+/// Accumulates text in Rust and converts it to a [`GString`] once at the end.
+///
+/// `GString` is immutable from Rust's point of view, so appending to one creates a new Godot string each time, copying everything
+/// accumulated so far. Building a string from many pieces that way makes a Godot allocation per piece and takes quadratic time.
+/// This builder collects the pieces in a Rust `String` instead, and crosses the FFI boundary only in [`build()`][Self::build].
+///
+/// The builder also implements [`fmt::Write`], so it can be used with `write!` and `writeln!`.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::GString;
+/// use godot::builtin::strings::GStringBuilder;
+///
+/// let mut builder = GStringBuilder::new();
+/// for name in ["Goblin", "Orc", "Troll"] {
+///     builder.push_str(name);
+///     builder.push(';');
+/// }
+///
+/// assert_eq!(builder.build(), GString::from("Goblin;Orc;Troll;"));
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct GStringBuilder {
+    buffer: String,
+}
+
+impl GStringBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty builder that can hold at least `capacity` bytes of UTF-8 without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: String::with_capacity(capacity),
+        }
+    }
+
+    /// Appends `s` to the end of the accumulated text.
+    pub fn push_str(&mut self, s: &str) {
+        self.buffer.push_str(s);
+    }
+
+    /// Appends a single character to the end of the accumulated text.
+    pub fn push(&mut self, ch: char) {
+        self.buffer.push(ch);
+    }
+
+    /// Returns the length of the accumulated text in bytes of UTF-8, not in characters.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if no text has been accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Converts the accumulated text into a `GString`.
+    pub fn build(self) -> GString {
+        GString::from(self.buffer)
+    }
+}
+
+impl fmt::Write for GStringBuilder {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
@@ -8,16 +8,25 @@
 //! Godot-types that are Strings.
 
 mod gstring;
+mod gstring_builder;
 mod macros;
 mod node_path;
 mod string_name;
+mod string_name_map;
 
 use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, GodotConvert, ToGodot};
 
 pub use gstring::*;
-pub use node_path::NodePath;
+pub use gstring_builder::GStringBuilder;
+pub use node_path::{
+    coerce_to_node_path, CaseInsensitiveNodePath, HashedNodePath, NodePath, NodePathComponent,
+    NodePathComponents, NodePathKey, NodePathVisitor, PropertyPath, StrictNodePathBuilder,
+};
+#[cfg(feature = "serde")]
+pub use node_path::{deserialize_node_path_seq, serde_empty_as_none};
 pub use string_name::{StringName, TransientStringNameOrd};
+pub use string_name_map::StringNameMap;
 
 impl GodotConvert for &str {
     type Via = GString;
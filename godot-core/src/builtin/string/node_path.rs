@@ -5,12 +5,20 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashSet;
 use std::fmt;
 
 use godot_ffi as sys;
 use godot_ffi::{ffi_methods, GodotFfi};
 
-use crate::builtin::inner;
+use crate::builtin::{inner, Variant, VariantType};
+#[cfg(since_api = "4.2")]
+use crate::classes::SceneTree;
+use crate::meta::error::{
+    ComponentError, LiteralParseError, NodePathDecodeError, NodePathEncodingError, NodePathError,
+};
+#[cfg(since_api = "4.2")]
+use crate::obj::{Gd, InstanceId};
 
 use super::{GString, StringName};
 
@@ -21,6 +29,18 @@ use super::{GString, StringName};
 /// Note that Godot ignores any bytes after a null-byte. This means that for instance `"hello, world!"` and `"hello, world!\0 ignored by Godot"`
 /// will be treated as the same string if converted to a `NodePath`.
 ///
+/// # Hash maps
+///
+/// `NodePath` can be used as a key in `HashMap` and `HashSet`. Lookups take the key by reference (`map.get(&path)`), so querying with an
+/// existing path neither clones nor allocates; no separate borrowed type is needed. Both `Hash` and `Eq` are delegated to Godot.
+///
+/// # Performance
+///
+/// A `NodePath` is a pointer to reference-counted, immutable data owned by Godot. Cloning and dropping only change the reference count,
+/// while the expensive part is parsing a string into a new path. There is therefore nothing to gain from pooling `NodePath` instances:
+/// a recycled instance cannot be re-pointed to a different path without parsing anew. When the same paths are needed repeatedly, keep
+/// them around and clone them instead of converting from strings each time.
+///
 /// # All string types
 ///
 /// | Intended use case | String type                                |
@@ -28,33 +48,1600 @@ use super::{GString, StringName};
 /// | General purpose   | [`GString`][crate::builtin::GString]       |
 /// | Interned names    | [`StringName`][crate::builtin::StringName] |
 /// | Scene-node paths  | **`NodePath`**                             |
+// We rely on `transparent` for `borrow_from_sys`.
+#[repr(transparent)]
 pub struct NodePath {
     opaque: sys::types::OpaqueNodePath,
 }
 
-impl NodePath {
-    fn from_opaque(opaque: sys::types::OpaqueNodePath) -> Self {
-        Self { opaque }
+impl NodePath {
+    /// Version tag of the format written by [`to_bytes()`][Self::to_bytes].
+    const WIRE_FORMAT_VERSION: u8 = 1;
+
+    fn from_opaque(opaque: sys::types::OpaqueNodePath) -> Self {
+        Self { opaque }
+    }
+
+    /// Parses a path from `path`, returning an error if it is not well-formed.
+    ///
+    /// Godot silently accepts many odd inputs, for example by collapsing `//` or ignoring a trailing `:`. This constructor is stricter
+    /// and rejects:
+    /// - empty names, e.g. `"A//B"` or `"A/"` (a single `"/"` for the root is allowed),
+    /// - empty subnames, e.g. `"Node:"` or `"Node::prop"`,
+    /// - names containing `.` (except for the special names `.` and `..`), `"`, or a `%` other than the leading unique-name marker,
+    /// - names or subnames containing control characters, including null bytes.
+    ///
+    /// The empty string is valid and yields an empty path.
+    pub fn try_new(path: &str) -> Result<NodePath, NodePathError> {
+        validate_path(path)?;
+        Ok(NodePath::from(path))
+    }
+
+    /// Assembles a path from its names, subnames and absoluteness, placing the `/` and `:` separators.
+    ///
+    /// The result is parsed once by Godot. Components are not validated; a name containing `/` or `:` will be split into several.
+    /// With no names and no subnames, `absolute = true` yields the root path `"/"`, and `absolute = false` the empty path.
+    ///
+    /// ```no_run
+    /// # use godot::builtin::{NodePath, StringName};
+    /// let path = NodePath::from_parts(&["A".into(), "B".into()], &["position".into()], true);
+    /// assert_eq!(path, NodePath::from("/A/B:position"));
+    /// ```
+    pub fn from_parts(names: &[StringName], subnames: &[StringName], absolute: bool) -> NodePath {
+        Self::from_parts_unchecked(absolute, names, subnames)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_inner().is_empty()
+    }
+
+    /// Returns the number of characters (Unicode scalar values) in the textual form of this path.
+    ///
+    /// Godot does not store the textual form of a `NodePath`, so this builds a temporary [`GString`] from the names and subnames.
+    /// It does not allocate a Rust `String`, but the cost is still linear in the length of the path.
+    pub fn char_len(&self) -> usize {
+        GString::from(self).len()
+    }
+
+    /// Returns the number of bytes in the UTF-8 encoding of the textual form of this path.
+    ///
+    /// This is the length of `self.to_string()`, and thus suitable for pre-sizing buffers. Like [`char_len()`][Self::char_len], this
+    /// builds a temporary [`GString`], but does not encode it to UTF-8.
+    pub fn bytes_len(&self) -> usize {
+        GString::from(self)
+            .chars()
+            .iter()
+            .map(|ch| ch.len_utf8())
+            .sum()
+    }
+
+    /// Returns the first name of this path, or `None` if the path has no names.
+    ///
+    /// For `"/root/Main:position"`, this is `"root"`. Subnames are not considered.
+    pub fn first_name(&self) -> Option<StringName> {
+        let inner = self.as_inner();
+        (inner.get_name_count() > 0).then(|| inner.get_name(0))
+    }
+
+    /// Returns the last name of this path, or `None` if the path has no names.
+    ///
+    /// For `"/root/Main:position"`, this is `"Main"`. Subnames are not considered.
+    pub fn last_name(&self) -> Option<StringName> {
+        let inner = self.as_inner();
+        let count = inner.get_name_count();
+        (count > 0).then(|| inner.get_name(count - 1))
+    }
+
+    /// ⚠️ Returns the name at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds. If you want to handle out-of-bounds access, use [`try_get_name()`](Self::try_get_name) instead.
+    pub fn get_name(&self, index: usize) -> StringName {
+        self.try_get_name(index).unwrap_or_else(|| {
+            let count = self.as_inner().get_name_count();
+            panic!("NodePath name index {index} is out of bounds: name count is {count}")
+        })
+    }
+
+    /// Returns the name at `index`, or `None` if the index is out of bounds.
+    ///
+    /// For `"/root/Main:position"`, index 1 yields `"Main"`. Subnames are not considered; see [`try_get_subname()`][Self::try_get_subname].
+    pub fn try_get_name(&self, index: usize) -> Option<StringName> {
+        let inner = self.as_inner();
+        (index < inner.get_name_count() as usize).then(|| inner.get_name(index as i64))
+    }
+
+    /// ⚠️ Returns the subname at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds. If you want to handle out-of-bounds access, use [`try_get_subname()`](Self::try_get_subname)
+    /// instead.
+    pub fn get_subname(&self, index: usize) -> StringName {
+        self.try_get_subname(index).unwrap_or_else(|| {
+            let count = self.as_inner().get_subname_count();
+            panic!("NodePath subname index {index} is out of bounds: subname count is {count}")
+        })
+    }
+
+    /// Returns the subname at `index`, or `None` if the index is out of bounds.
+    ///
+    /// For `"/root/Main:position:x"`, index 0 yields `"position"`.
+    pub fn try_get_subname(&self, index: usize) -> Option<StringName> {
+        let inner = self.as_inner();
+        (index < inner.get_subname_count() as usize).then(|| inner.get_subname(index as i64))
+    }
+
+    /// Splits off the first name, returning it together with the remaining path, or `None` if the path has no names.
+    ///
+    /// The remainder is always relative, and it always keeps all subnames of `self`. For `"/A/B/C:prop"`, this yields `"A"` and
+    /// `"B/C:prop"`. If the path has a single name, the remainder is empty apart from subnames, e.g. `"A:prop"` yields `"A"` and
+    /// `":prop"`. This makes it suitable for resolving a path recursively, one node at a time:
+    ///
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    /// use godot::classes::Node;
+    /// use godot::obj::Gd;
+    ///
+    /// fn resolve(node: Gd<Node>, path: &NodePath) -> Option<Gd<Node>> {
+    ///     match path.split_first_name() {
+    ///         Some((name, rest)) => resolve(node.get_node_or_null(NodePath::from(&name))?, &rest),
+    ///         None => Some(node),
+    ///     }
+    /// }
+    /// ```
+    pub fn split_first_name(&self) -> Option<(StringName, NodePath)> {
+        let names = self.name_vec();
+        let (first, rest) = names.split_first()?;
+
+        let rest = Self::from_parts_unchecked(false, rest, &self.subname_vec());
+        Some((first.clone(), rest))
+    }
+
+    /// Returns `true` if every name and subname of this path is a valid identifier, and there is at least one.
+    ///
+    /// An identifier starts with an ASCII letter or `_`, followed by ASCII letters, digits or `_`. Godot itself accepts far more in
+    /// node paths, such as spaces, leading digits or `.`/`..` names; this check is meant for data-driven systems that want to reject
+    /// such input as a likely typo. Whether the path is absolute does not matter.
+    ///
+    /// _Godot equivalent: `is_valid_identifier` on each component_
+    #[doc(alias = "is_valid_identifier")]
+    pub fn is_strict_identifier_path(&self) -> bool {
+        let mut components = self.iter_components().peekable();
+        if components.peek().is_none() {
+            return false;
+        }
+
+        components.all(|component| {
+            GString::from(component.as_string_name())
+                .as_inner()
+                .is_valid_identifier()
+        })
+    }
+
+    /// Returns `true` if this path addresses a property or sub-resource, i.e. it has at least one subname.
+    ///
+    /// `"Node:prop"` is a property path, while `"Node"` is not. Property paths are typically resolved with `Object::get_indexed()`,
+    /// whereas plain node paths are resolved with `Node::get_node()`.
+    pub fn is_property_path(&self) -> bool {
+        self.as_inner().get_subname_count() > 0
+    }
+
+    /// Converts this path into a [`PropertyPath`], by turning all names into subnames.
+    ///
+    /// For example, `"A/B:c"` becomes `":A/B:c"`. Fails only if this path is empty.
+    ///
+    /// _Godot equivalent: `get_as_property_path`_
+    #[doc(alias = "get_as_property_path")]
+    pub fn to_property_path(&self) -> Result<PropertyPath, NodePathError> {
+        PropertyPath::try_from(self.as_inner().get_as_property_path())
+    }
+
+    /// Returns an iterator over all names and subnames of this path, in source order.
+    ///
+    /// Names (separated by `/`) are yielded first, followed by subnames (separated by `:`). In contrast to querying names and subnames
+    /// separately, this preserves the distinction between the two, so the original path can be reconstructed faithfully.
+    /// Whether the path is absolute is not part of the components; see `is_absolute()` on [`as_inner()`][Self::as_inner].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    /// use godot::builtin::strings::NodePathComponent;
+    ///
+    /// let path = NodePath::from("Parent/Child:position:x");
+    /// let components: Vec<_> = path.iter_components().collect();
+    ///
+    /// assert_eq!(components, [
+    ///     NodePathComponent::Name("Parent".into()),
+    ///     NodePathComponent::Name("Child".into()),
+    ///     NodePathComponent::Subname("position".into()),
+    ///     NodePathComponent::Subname("x".into()),
+    /// ]);
+    /// ```
+    pub fn iter_components(&self) -> impl Iterator<Item = NodePathComponent> + '_ {
+        let inner = self.as_inner();
+        let names = (0..inner.get_name_count())
+            .map(move |i| NodePathComponent::Name(self.as_inner().get_name(i)));
+        let subnames = (0..inner.get_subname_count())
+            .map(move |i| NodePathComponent::Subname(self.as_inner().get_subname(i)));
+
+        names.chain(subnames)
+    }
+
+    /// Reads all names and subnames of this path at once, into a snapshot that can be iterated and indexed without further FFI calls.
+    ///
+    /// Each query on a `NodePath` goes through Godot, including every step of [`iter_components()`][Self::iter_components]. If the
+    /// components are read many times, e.g. when matching one path against many patterns, taking a snapshot first is faster.
+    pub fn components(&self) -> NodePathComponents {
+        NodePathComponents {
+            names: self.name_vec(),
+            subnames: self.subname_vec(),
+            is_absolute: self.as_inner().is_absolute(),
+        }
+    }
+
+    /// Drives `visitor` over the components of this path: first all names, then all subnames, each in order.
+    ///
+    /// This is an alternative to [`iter_components()`][Self::iter_components] for traversals that accumulate state across components,
+    /// such as custom formatting. The index passed to the visitor counts names and subnames separately, both starting at 0.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::{NodePath, StringName};
+    /// use godot::builtin::strings::NodePathVisitor;
+    ///
+    /// struct Depth(usize);
+    ///
+    /// impl NodePathVisitor for Depth {
+    ///     fn visit_name(&mut self, _index: usize, _name: &StringName) {
+    ///         self.0 += 1;
+    ///     }
+    ///
+    ///     fn visit_subname(&mut self, _index: usize, _subname: &StringName) {}
+    /// }
+    ///
+    /// let mut depth = Depth(0);
+    /// NodePath::from("A/B/C:prop").accept(&mut depth);
+    /// assert_eq!(depth.0, 3);
+    /// ```
+    pub fn accept(&self, visitor: &mut impl NodePathVisitor) {
+        let inner = self.as_inner();
+
+        for i in 0..inner.get_name_count() {
+            visitor.visit_name(i as usize, &inner.get_name(i));
+        }
+        for i in 0..inner.get_subname_count() {
+            visitor.visit_subname(i as usize, &inner.get_subname(i));
+        }
+    }
+
+    /// Returns `true` if any name of this path equals `name`.
+    ///
+    /// Whole names are compared, so `"UI/HealthBar"` contains `"UI"` but not `"Health"`. Subnames are not considered; see
+    /// [`contains_subname()`][Self::contains_subname].
+    pub fn contains_name(&self, name: &StringName) -> bool {
+        self.iter_components()
+            .any(|component| matches!(component, NodePathComponent::Name(n) if &n == name))
+    }
+
+    /// Returns `true` if any subname of this path equals `subname`.
+    ///
+    /// Whole subnames are compared, so `"Sprite:modulate:a"` contains `"modulate"` but not `"mod"`. Names are not considered; see
+    /// [`contains_name()`][Self::contains_name].
+    pub fn contains_subname(&self, subname: &StringName) -> bool {
+        self.iter_components()
+            .any(|component| matches!(component, NodePathComponent::Subname(n) if &n == subname))
+    }
+
+    /// Returns `true` if this path matches the given glob-like `pattern`.
+    ///
+    /// The pattern is split into components just like a node path:
+    /// - `*` matches exactly one component.
+    /// - `**` matches zero or more components.
+    /// - Any other component must match literally.
+    ///
+    /// A leading `/` in the pattern only matches absolute paths, and vice versa. Subnames are ignored, unless the pattern itself contains
+    /// a `:`; in that case, the part after the first `:` is matched against the subnames using the same rules.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let path = NodePath::from("Level/Enemies/Goblin/Sprite:modulate");
+    ///
+    /// assert!(path.matches_glob("**/Sprite"));
+    /// assert!(path.matches_glob("Level/*/*/Sprite"));
+    /// assert!(path.matches_glob("Level/**:modulate"));
+    /// assert!(!path.matches_glob("*/Sprite"));
+    /// ```
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        let inner = self.as_inner();
+
+        let (pattern, pattern_absolute) = match pattern.strip_prefix('/') {
+            Some(stripped) => (stripped, true),
+            None => (pattern, false),
+        };
+
+        if pattern_absolute != inner.is_absolute() {
+            return false;
+        }
+
+        let (name_pattern, subname_pattern) = match pattern.split_once(':') {
+            Some((names, subnames)) => (names, Some(subnames)),
+            None => (pattern, None),
+        };
+
+        let names: Vec<String> = (0..inner.get_name_count())
+            .map(|i| inner.get_name(i).to_string())
+            .collect();
+
+        if !glob_match(&split_glob_pattern(name_pattern, '/'), &names) {
+            return false;
+        }
+
+        match subname_pattern {
+            Some(subname_pattern) => {
+                let subnames: Vec<String> = (0..inner.get_subname_count())
+                    .map(|i| inner.get_subname(i).to_string())
+                    .collect();
+
+                glob_match(&split_glob_pattern(subname_pattern, ':'), &subnames)
+            }
+            None => true,
+        }
+    }
+
+    /// Returns the node part of this path, with all subnames removed.
+    ///
+    /// For example, `"Parent/Node:position:x"` becomes `"Parent/Node"`. Paths without subnames are returned unchanged.
+    #[doc(alias = "clear_subnames")]
+    pub fn without_subnames(&self) -> NodePath {
+        self.truncate_subnames(0)
+    }
+
+    /// Returns a copy of this path that keeps only the first `keep` subnames.
+    ///
+    /// For example, `"Node:a:b:c"` truncated to 1 subname becomes `"Node:a"`. If `keep` is greater than or equal to the number of
+    /// subnames, the path is returned unchanged; `keep == 0` is equivalent to [`without_subnames()`][Self::without_subnames].
+    pub fn truncate_subnames(&self, keep: usize) -> NodePath {
+        let subnames = self.subname_vec();
+        if keep >= subnames.len() {
+            return self.clone();
+        }
+
+        Self::from_parts_unchecked(
+            self.as_inner().is_absolute(),
+            &self.name_vec(),
+            &subnames[..keep],
+        )
+    }
+
+    /// ⚠️ Returns a copy of this path with the subname at `index` replaced by `new_subname`.
+    ///
+    /// Names and whether the path is absolute are preserved. For example, replacing subname 1 of `"Node:position:x"` with `"y"` gives
+    /// `"Node:position:y"`. The new subname is not validated; if it contains `:`, it is parsed as multiple subnames.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn replace_subname(&self, index: usize, new_subname: &StringName) -> NodePath {
+        self.splice_subnames(index..index + 1, std::slice::from_ref(new_subname))
+    }
+
+    /// ⚠️ Returns a copy of this path with the subnames in `range` replaced by `replacement`.
+    ///
+    /// `replacement` may be longer or shorter than `range`, so the number of subnames can grow or shrink; an empty `range` inserts at
+    /// its start. Names and whether the path is absolute are preserved.
+    ///
+    /// # Panics
+    /// If the start of the range is greater than its end, or if the end is greater than the number of subnames.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::{NodePath, StringName};
+    ///
+    /// let path = NodePath::from("Node:material:albedo_color:r");
+    /// let migrated = path.splice_subnames(0..2, &["surface".into(), "base".into(), "color".into()]);
+    ///
+    /// assert_eq!(migrated, NodePath::from("Node:surface:base:color:r"));
+    /// ```
+    #[doc(alias = "replace_subname_range")]
+    pub fn splice_subnames(
+        &self,
+        range: std::ops::Range<usize>,
+        replacement: &[StringName],
+    ) -> NodePath {
+        let mut subnames = self.subname_vec();
+        let len = subnames.len();
+
+        assert!(
+            range.start <= range.end,
+            "NodePath subname range starts at {} but ends at {}",
+            range.start,
+            range.end
+        );
+        assert!(
+            range.end <= len,
+            "NodePath subname range end {} is out of bounds: subname count is {len}",
+            range.end
+        );
+
+        subnames.splice(range, replacement.iter().cloned());
+        Self::from_parts_unchecked(self.as_inner().is_absolute(), &self.name_vec(), &subnames)
+    }
+
+    /// ⚠️ Returns a new path containing only the names in `range`.
+    ///
+    /// The result is absolute only if this path is absolute and `range` starts at 0. Subnames are kept only if `range` extends to the
+    /// last name. An empty range yields an empty path.
+    ///
+    /// # Panics
+    /// If the start of the range is greater than its end, or if the end is greater than the number of names.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let path = NodePath::from("/A/B/C/D:prop");
+    ///
+    /// assert_eq!(path.slice_names(1..3), NodePath::from("B/C"));
+    /// assert_eq!(path.slice_names(..2), NodePath::from("/A/B"));
+    /// assert_eq!(path.slice_names(2..), NodePath::from("C/D:prop"));
+    /// ```
+    #[doc(alias = "segment_range")]
+    pub fn slice_names(&self, range: impl std::ops::RangeBounds<usize>) -> NodePath {
+        use std::ops::Bound;
+
+        let names = self.name_vec();
+        let len = names.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        assert!(
+            start <= end,
+            "NodePath name range starts at {start} but ends at {end}"
+        );
+        assert!(
+            end <= len,
+            "NodePath name range end {end} is out of bounds: name count is {len}"
+        );
+
+        if start == end {
+            return NodePath::default();
+        }
+
+        let is_absolute = start == 0 && self.as_inner().is_absolute();
+        let subnames = if end == len {
+            self.subname_vec()
+        } else {
+            Vec::new()
+        };
+
+        Self::from_parts_unchecked(is_absolute, &names[start..end], &subnames)
+    }
+
+    /// Returns a copy of this path with exactly `target` names, padding with `filler` or trimming from the end as needed.
+    ///
+    /// A shorter path gets copies of `filler` appended after its last name, keeping its subnames. A longer path is cut like
+    /// [`slice_names(..target)`][Self::slice_names], so its subnames are dropped. Whether the path is absolute is preserved, except that
+    /// trimming to 0 names yields an empty path.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::{NodePath, StringName};
+    ///
+    /// let filler = StringName::from("_");
+    ///
+    /// assert_eq!(NodePath::from("A:prop").align_depth(3, &filler), NodePath::from("A/_/_:prop"));
+    /// assert_eq!(NodePath::from("/A/B/C:prop").align_depth(2, &filler), NodePath::from("/A/B"));
+    /// ```
+    pub fn align_depth(&self, target: usize, filler: &StringName) -> NodePath {
+        let mut names = self.name_vec();
+        let len = names.len();
+
+        if len > target {
+            return self.slice_names(..target);
+        }
+        if len == target {
+            return self.clone();
+        }
+
+        names.resize(target, filler.clone());
+        Self::from_parts_unchecked(self.as_inner().is_absolute(), &names, &self.subname_vec())
+    }
+
+    /// Returns a simplified copy of this path, following Godot's rules.
+    ///
+    /// `"."` names are removed, and each `".."` name is removed together with the name before it, e.g. `"A/./B/../C"` becomes `"A/C"`.
+    /// Leading `".."` names that have nothing to cancel are kept, so `"../A"` is unchanged. Subnames and whether the path is absolute
+    /// are preserved. If all names cancel out, the single name `"."` remains. Simplifying an already simplified path returns an equal path.
+    ///
+    /// Godot does not expose this operation to extensions, so it is implemented in Rust, following the engine's `NodePath::simplify()`.
+    #[doc(alias = "normalize")]
+    pub fn simplified(&self) -> NodePath {
+        let names: Vec<String> = self.name_vec().iter().map(StringName::to_string).collect();
+        let mut simplified: Vec<&str> = Vec::with_capacity(names.len());
+
+        for name in &names {
+            match name.as_str() {
+                "." => {}
+                ".." if simplified.last().is_some_and(|&last| last != "..") => {
+                    simplified.pop();
+                }
+                name => simplified.push(name),
+            }
+        }
+
+        if simplified.is_empty() && !names.is_empty() {
+            simplified.push(".");
+        }
+
+        let simplified: Vec<StringName> = simplified.into_iter().map(StringName::from).collect();
+        Self::from_parts_unchecked(
+            self.as_inner().is_absolute(),
+            &simplified,
+            &self.subname_vec(),
+        )
+    }
+
+    /// Returns a copy of this path in which runs of consecutive identical names are collapsed into one.
+    ///
+    /// For example, `"A/A/B/B/B/C"` becomes `"A/B/C"`. This is useful to clean up paths produced by joins that overlap. Subnames and
+    /// whether the path is absolute are preserved; subnames themselves are not deduplicated. Note that `".."` is a name like any
+    /// other, so `"../../X"` becomes `"../X"`.
+    pub fn dedup_adjacent_names(&self) -> NodePath {
+        let mut names = self.name_vec();
+        let len = names.len();
+
+        names.dedup();
+        if names.len() == len {
+            return self.clone();
+        }
+
+        Self::from_parts_unchecked(self.as_inner().is_absolute(), &names, &self.subname_vec())
+    }
+
+    /// Returns a copy of this path that is absolute if `absolute` is `true`, and relative otherwise.
+    ///
+    /// Only the leading `/` is added or removed; names and subnames are kept as they are. If the path already has the requested
+    /// absoluteness, it is returned unchanged. Note that the root path `"/"` made relative becomes the empty path.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// assert_eq!(NodePath::from("A/B").with_absolute(true), NodePath::from("/A/B"));
+    /// assert_eq!(NodePath::from("/A/B:prop").with_absolute(false), NodePath::from("A/B:prop"));
+    /// ```
+    pub fn with_absolute(&self, absolute: bool) -> NodePath {
+        if self.as_inner().is_absolute() == absolute {
+            return self.clone();
+        }
+
+        Self::from_parts_unchecked(absolute, &self.name_vec(), &self.subname_vec())
+    }
+
+    /// Returns `true` if the names of `prefix` are the leading names of this path, and both paths are either absolute or relative.
+    ///
+    /// Unlike a string comparison, this compares whole names, so `"/root/MainMenu"` does not start with `"/root/Main"`. A path starts
+    /// with itself, and every path starts with an empty path of the same absoluteness. Subnames of both paths are ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let path = NodePath::from("/root/Main/Player");
+    ///
+    /// assert!(path.starts_with(&NodePath::from("/root/Main")));
+    /// assert!(!NodePath::from("/root/MainMenu").starts_with(&NodePath::from("/root/Main")));
+    /// assert!(!path.starts_with(&NodePath::from("root/Main")));
+    /// ```
+    pub fn starts_with(&self, prefix: &NodePath) -> bool {
+        if self.as_inner().is_absolute() != prefix.as_inner().is_absolute() {
+            return false;
+        }
+
+        let names = self.name_vec();
+        let prefix_names = prefix.name_vec();
+
+        names.starts_with(&prefix_names)
+    }
+
+    /// Pairs up the names of this path and `other` by position, for finding where the two paths diverge.
+    ///
+    /// The iterator has as many items as the longer path has names; once the shorter path runs out, its side is `None`. Subnames and
+    /// whether the paths are absolute are not considered.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let a = NodePath::from("Level/Enemies/Goblin");
+    /// let b = NodePath::from("Level/Items");
+    ///
+    /// let first_difference = a.diff(&b).position(|(a, b)| a != b);
+    /// assert_eq!(first_difference, Some(1));
+    /// ```
+    #[doc(alias = "zip_with")]
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a NodePath,
+    ) -> impl Iterator<Item = (Option<StringName>, Option<StringName>)> + 'a {
+        let mut names = self.name_vec().into_iter();
+        let mut other_names = other.name_vec().into_iter();
+
+        std::iter::from_fn(move || match (names.next(), other_names.next()) {
+            (None, None) => None,
+            pair => Some(pair),
+        })
+    }
+
+    /// Compares the textual forms of two paths in natural order, so that `"Item2"` sorts before `"Item10"`.
+    ///
+    /// The texts are split into alternating runs of ASCII digits and other characters. Digit runs are compared by their numeric value,
+    /// other runs character by character (case-sensitive). Paths whose runs are all equal, such as `"Item01"` and `"Item1"`, fall back
+    /// to plain string comparison, so the ordering is total and only returns [`Equal`][std::cmp::Ordering::Equal] for equal paths.
+    ///
+    /// This is the order users expect in listings like a file dock. It is not related to Godot's `<` operator on `NodePath`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let mut paths = vec![NodePath::from("Item10"), NodePath::from("Item2"), NodePath::from("Item1")];
+    /// paths.sort_by(NodePath::cmp_natural);
+    ///
+    /// assert_eq!(paths, [NodePath::from("Item1"), NodePath::from("Item2"), NodePath::from("Item10")]);
+    /// ```
+    pub fn cmp_natural(&self, other: &NodePath) -> std::cmp::Ordering {
+        natural_cmp(&self.to_string(), &other.to_string())
+    }
+
+    /// Returns the longest path in `candidates` that this path [starts with][Self::starts_with], or `None` if there is none.
+    ///
+    /// "Longest" refers to the number of names. If multiple candidates have the same length, e.g. because they differ only in
+    /// subnames, the first one in `candidates` is returned. This is the lookup step of a routing table keyed by base paths.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let routes = [NodePath::from("UI"), NodePath::from("UI/Inventory"), NodePath::from("World")];
+    ///
+    /// let path = NodePath::from("UI/Inventory/Slot3");
+    /// assert_eq!(path.longest_prefix_in(&routes), Some(&routes[1]));
+    /// assert_eq!(NodePath::from("Audio").longest_prefix_in(&routes), None);
+    /// ```
+    pub fn longest_prefix_in<'a>(&self, candidates: &'a [NodePath]) -> Option<&'a NodePath> {
+        let is_absolute = self.as_inner().is_absolute();
+        let names = self.name_vec();
+
+        let mut longest: Option<(&NodePath, usize)> = None;
+        for candidate in candidates {
+            if candidate.as_inner().is_absolute() != is_absolute {
+                continue;
+            }
+
+            let candidate_names = candidate.name_vec();
+            let is_longer = match longest {
+                Some((_, len)) => candidate_names.len() > len,
+                None => true,
+            };
+
+            if is_longer && names.starts_with(&candidate_names) {
+                longest = Some((candidate, candidate_names.len()));
+            }
+        }
+
+        longest.map(|(candidate, _)| candidate)
+    }
+
+    /// Returns the relative path that leads from `base` to this path, or `None` if either path is not absolute.
+    ///
+    /// This follows the semantics of `Node::get_path_to()`: the result goes up from `base` with `".."` names until reaching the
+    /// deepest common ancestor, then down to `self`. Subnames of `self` are kept, while subnames of `base` are ignored. If both paths
+    /// address the same node, the result is `"."` (plus any subnames of `self`).
+    ///
+    /// The result is computed purely from the names, without consulting the scene tree. In particular, `"."` and `".."` names in the
+    /// inputs are not resolved; pass [simplified][Self::simplified] paths if they may occur.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let base = NodePath::from("/root/Level/Enemies");
+    ///
+    /// let child = NodePath::from("/root/Level/Enemies/Goblin");
+    /// assert_eq!(child.relative_to(&base), Some(NodePath::from("Goblin")));
+    ///
+    /// let sibling = NodePath::from("/root/Level/Player:position");
+    /// assert_eq!(sibling.relative_to(&base), Some(NodePath::from("../Player:position")));
+    ///
+    /// assert_eq!(NodePath::from("Player").relative_to(&base), None);
+    /// ```
+    pub fn relative_to(&self, base: &NodePath) -> Option<NodePath> {
+        if !self.as_inner().is_absolute() || !base.as_inner().is_absolute() {
+            return None;
+        }
+
+        let names = self.name_vec();
+        let base_names = base.name_vec();
+
+        let common = names
+            .iter()
+            .zip(base_names.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let up = StringName::from("..");
+        let mut relative: Vec<StringName> = std::iter::repeat(up)
+            .take(base_names.len() - common)
+            .chain(names[common..].iter().cloned())
+            .collect();
+
+        if relative.is_empty() {
+            relative.push(StringName::from("."));
+        }
+
+        Some(Self::from_parts_unchecked(
+            false,
+            &relative,
+            &self.subname_vec(),
+        ))
+    }
+
+    /// Returns a new path with the names of `prefix` placed before the names of this path.
+    ///
+    /// The result is absolute if `prefix` is absolute. It keeps the subnames of `self`, while subnames of `prefix` are dropped.
+    /// Returns `None` if `self` is absolute, since an absolute path cannot be anchored under another one.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let path = NodePath::from("Player/Sprite:modulate");
+    /// let anchored = path.prepend(&NodePath::from("/root/Level"));
+    ///
+    /// assert_eq!(anchored, Some(NodePath::from("/root/Level/Player/Sprite:modulate")));
+    /// assert_eq!(NodePath::from("/root").prepend(&NodePath::from("A")), None);
+    /// ```
+    #[doc(alias = "push_front")]
+    pub fn prepend(&self, prefix: &NodePath) -> Option<NodePath> {
+        if self.as_inner().is_absolute() {
+            return None;
+        }
+
+        let mut names = prefix.name_vec();
+        names.extend(self.name_vec());
+
+        Some(Self::from_parts_unchecked(
+            prefix.as_inner().is_absolute(),
+            &names,
+            &self.subname_vec(),
+        ))
+    }
+
+    /// Returns a new path with `f` applied to each name, keeping the subnames and whether the path is absolute.
+    ///
+    /// Subnames are not passed to `f`. The returned names are not validated; a name containing `/` or `:` is parsed as multiple
+    /// components.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::{NodePath, StringName};
+    ///
+    /// let path = NodePath::from("/A/B:prop");
+    /// let prefixed = path.map_names(|name| StringName::from(format!("pre_{name}")));
+    ///
+    /// assert_eq!(prefixed, NodePath::from("/pre_A/pre_B:prop"));
+    /// ```
+    pub fn map_names(&self, f: impl Fn(&StringName) -> StringName) -> NodePath {
+        let names: Vec<StringName> = self.name_vec().iter().map(f).collect();
+
+        Self::from_parts_unchecked(self.as_inner().is_absolute(), &names, &self.subname_vec())
+    }
+
+    /// Returns a new path with only the names for which `pred` returns `true`, keeping the subnames and whether the path is absolute.
+    ///
+    /// Subnames are not passed to `pred`. If all names are removed, the result consists of the subnames only (e.g. `":prop"`), or is
+    /// empty (or `"/"` for absolute paths) if there are none.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::{NodePath, StringName};
+    ///
+    /// let wrapper = StringName::from("Container");
+    /// let path = NodePath::from("UI/Container/HealthBar:value");
+    /// let stripped = path.retain_names(|name| *name != wrapper);
+    ///
+    /// assert_eq!(stripped, NodePath::from("UI/HealthBar:value"));
+    /// ```
+    pub fn retain_names(&self, pred: impl Fn(&StringName) -> bool) -> NodePath {
+        let mut names = self.name_vec();
+        let len = names.len();
+
+        names.retain(pred);
+        if names.len() == len {
+            return self.clone();
+        }
+
+        Self::from_parts_unchecked(self.as_inner().is_absolute(), &names, &self.subname_vec())
+    }
+
+    /// Appends `tail` to this path, parsing the combined string only once.
+    ///
+    /// This is equivalent to joining with `NodePath::from(tail)`, but avoids parsing `tail` on its own. `tail` may consist of multiple
+    /// names (`"A/B"`) and may carry subnames (`"A:prop"`). Names of `tail` are appended to the names of this path, and subnames of `tail`
+    /// are appended after the subnames of this path. The absolute flag of `self` is preserved; a leading `/` in `tail` is ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let path = NodePath::from("/root/Main");
+    /// assert_eq!(path.concat_str("Player/Sprite"), NodePath::from("/root/Main/Player/Sprite"));
+    /// assert_eq!(path.concat_str("Player:position"), NodePath::from("/root/Main/Player:position"));
+    /// ```
+    pub fn concat_str(&self, tail: &str) -> NodePath {
+        let (tail_names, tail_subnames) = match tail.split_once(':') {
+            Some((names, subnames)) => (names, Some(subnames)),
+            None => (tail, None),
+        };
+        let tail_names = tail_names.trim_start_matches('/');
+
+        let names = self.name_vec();
+        let mut path = Self::parts_to_string(self.as_inner().is_absolute(), &names, &[]);
+
+        if !names.is_empty() && !tail_names.is_empty() {
+            path.push('/');
+        }
+        path.push_str(tail_names);
+
+        for subname in self.subname_vec() {
+            path.push(':');
+            path.push_str(&subname.to_string());
+        }
+
+        if let Some(tail_subnames) = tail_subnames {
+            path.push(':');
+            path.push_str(tail_subnames);
+        }
+
+        NodePath::from(path)
+    }
+
+    /// Returns the path text, shortened to at most `max_chars` characters by replacing names in the middle with `...`.
+    ///
+    /// Whole names are elided, never parts of one. The first name and the last name (including any subnames) are always kept, and as
+    /// many names before the last one as fit. For example, `"/root/Level/World/Enemies/Goblin"` with a limit of 24 becomes
+    /// `"/root/.../Enemies/Goblin"`.
+    ///
+    /// If the path already fits, it is returned unchanged, as it is when eliding would not make it any shorter (e.g. `"A/B/C"`, since
+    /// `"A/.../C"` is longer). If even the first and last names do not fit, the result is longer than `max_chars`; callers that need
+    /// a hard limit must truncate further. Lengths are counted in Unicode scalar values, which
+    /// approximates but does not equal display width.
+    pub fn elide_middle(&self, max_chars: usize) -> String {
+        let full = self.to_string();
+        if full.chars().count() <= max_chars {
+            return full;
+        }
+
+        let names = self.name_vec();
+        if names.len() <= 2 {
+            return full;
+        }
+
+        let mut head = Self::parts_to_string(self.as_inner().is_absolute(), &names[..1], &[]);
+        head.push_str("/...");
+
+        let last = names.len() - 1;
+        let mut tail = Self::parts_to_string(false, &names[last..], &self.subname_vec());
+        let mut tail_len = tail.chars().count();
+
+        // Prepend further names while they fit. Not all of them can, since the full path is already too long.
+        let budget = max_chars.saturating_sub(head.chars().count());
+        for name in names[1..last].iter().rev() {
+            let name = name.to_string();
+            let len = name.chars().count() + 1;
+            if tail_len + 1 + len > budget {
+                break;
+            }
+
+            tail = format!("{name}/{tail}");
+            tail_len += len;
+        }
+
+        let elided = format!("{head}/{tail}");
+        if elided.chars().count() < full.chars().count() {
+            elided
+        } else {
+            full
+        }
+    }
+
+    /// Converts the path to a Rust `String`, failing if it contains content that could not be represented faithfully.
+    ///
+    /// Since Godot 4.1, strings are always stored as valid UTF-32, so converting to UTF-8 cannot fail by itself. Instead, Godot replaces
+    /// invalid input with U+FFFD (`�`) at the time the string is created -- for example when decoding malformed UTF-8 bytes or lone
+    /// surrogates. A `NodePath` inherits such replacements from the string it was parsed from. This method reports them as an error
+    /// rather than passing them on, unlike [`to_string()`][ToString::to_string].
+    ///
+    /// Because the original content is already lost, a path that deliberately contains U+FFFD is rejected as well.
+    pub fn try_to_string(&self) -> Result<String, NodePathEncodingError> {
+        let string = GString::from(self);
+        let chars = string.chars();
+
+        let replaced = chars
+            .iter()
+            .position(|&ch| ch == char::REPLACEMENT_CHARACTER);
+        match replaced {
+            Some(position) => Err(NodePathEncodingError::new(position)),
+            None => Ok(chars.iter().collect()),
+        }
+    }
+
+    /// Returns the path as a GDScript node path literal, e.g. `^"Parent/Child:position"`.
+    ///
+    /// Unlike the `Debug` output, the text is escaped so that the literal can be embedded in generated GDScript source: `"` and `\`
+    /// are backslash-escaped, newlines, tabs and carriage returns become `\n`, `\t` and `\r`, and other control characters are written
+    /// as `\uXXXX`. [`from_gdscript_literal()`][Self::from_gdscript_literal] parses the result back.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let path = NodePath::from(r#"Say "hi":text"#);
+    /// assert_eq!(path.to_gdscript_literal(), r#"^"Say \"hi\":text""#);
+    /// ```
+    pub fn to_gdscript_literal(&self) -> String {
+        let text = self.to_string();
+        let mut literal = String::with_capacity(text.len() + 3);
+
+        literal.push_str("^\"");
+        for ch in text.chars() {
+            match ch {
+                '"' => literal.push_str("\\\""),
+                '\\' => literal.push_str("\\\\"),
+                '\n' => literal.push_str("\\n"),
+                '\t' => literal.push_str("\\t"),
+                '\r' => literal.push_str("\\r"),
+                ch if ch.is_control() => literal.push_str(&format!("\\u{:04X}", ch as u32)),
+                ch => literal.push(ch),
+            }
+        }
+        literal.push('"');
+
+        literal
+    }
+
+    /// Parses a GDScript node path literal of the form `^"..."`, resolving escape sequences.
+    ///
+    /// Accepts the escapes written by [`to_gdscript_literal()`][Self::to_gdscript_literal], i.e. `\"`, `\\`, `\n`, `\t`, `\r` and
+    /// `\uXXXX`, as well as `\'`. The resulting text is parsed by Godot like [`NodePath::from()`], without further validation.
+    ///
+    /// # Errors
+    /// Returns [`LiteralParseError`] if `literal` is not enclosed in `^"` and `"`, contains an unescaped `"`, or contains an unknown or
+    /// incomplete escape sequence.
+    pub fn from_gdscript_literal(literal: &str) -> Result<NodePath, LiteralParseError> {
+        const PREFIX_LEN: usize = 2;
+
+        let body = literal
+            .strip_prefix("^\"")
+            .and_then(|rest| rest.strip_suffix('"'))
+            .ok_or(LiteralParseError::MissingDelimiters)?;
+
+        let mut text = String::with_capacity(body.len());
+        let mut chars = body.char_indices();
+        while let Some((index, ch)) = chars.next() {
+            let position = index + PREFIX_LEN;
+            match ch {
+                '"' => return Err(LiteralParseError::UnescapedQuote { position }),
+                '\\' => {
+                    let escaped = match chars.next().map(|(_, ch)| ch) {
+                        Some('"') => '"',
+                        Some('\'') => '\'',
+                        Some('\\') => '\\',
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('u') => {
+                            let hex: String = chars.by_ref().take(4).map(|(_, ch)| ch).collect();
+                            let is_valid =
+                                hex.len() == 4 && hex.chars().all(|ch| ch.is_ascii_hexdigit());
+
+                            u32::from_str_radix(&hex, 16)
+                                .ok()
+                                .filter(|_| is_valid)
+                                .and_then(char::from_u32)
+                                .ok_or(LiteralParseError::InvalidEscape { position })?
+                        }
+                        _ => return Err(LiteralParseError::InvalidEscape { position }),
+                    };
+                    text.push(escaped);
+                }
+                ch => text.push(ch),
+            }
+        }
+
+        Ok(NodePath::from(text))
+    }
+
+    /// Writes the path text to `writer`, as a little-endian `u32` byte length followed by the UTF-8 bytes.
+    ///
+    /// This is a minimal framing for hand-written binary formats; use [`decode()`][Self::decode] to read it back. For general-purpose
+    /// serialization, prefer the `serde` feature.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`InvalidInput`][std::io::ErrorKind::InvalidInput] if the text is longer than `u32::MAX` bytes, and
+    /// passes on any error from `writer`.
+    pub fn encode<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let text = self.to_string();
+        let len = u32::try_from(text.len()).map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "node path is too long to encode",
+            )
+        })?;
+
+        writer.write_all(&len.to_le_bytes())?;
+        writer.write_all(text.as_bytes())
+    }
+
+    /// Reads a path from `reader`, in the format written by [`encode()`][Self::encode].
+    ///
+    /// # Errors
+    /// Returns an error of kind [`InvalidData`][std::io::ErrorKind::InvalidData] if the bytes are not valid UTF-8, and
+    /// [`UnexpectedEof`][std::io::ErrorKind::UnexpectedEof] if `reader` ends before the announced length.
+    pub fn decode<R: std::io::Read>(reader: &mut R) -> std::io::Result<NodePath> {
+        let mut len = [0u8; 4];
+        reader.read_exact(&mut len)?;
+        let len = u32::from_le_bytes(len) as usize;
+
+        // Do not trust the length for pre-allocation; a corrupt prefix would otherwise allocate up to 4 GiB upfront.
+        let mut bytes = Vec::new();
+        reader.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+
+        let text = String::from_utf8(bytes)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+
+        Ok(NodePath::from(text))
+    }
+
+    /// Encodes the path in a fixed, versioned binary format suitable for exchange with other processes.
+    ///
+    /// The format (version 1) is:
+    /// 1. One byte version tag, currently `1`.
+    /// 2. Length of the text in bytes, as little-endian `u32`.
+    /// 3. The path text, UTF-8 encoded, without terminator.
+    ///
+    /// Use [`from_bytes()`][Self::from_bytes] to decode. Future versions of the format will use a different tag.
+    ///
+    /// # Panics
+    /// If the path text is longer than `u32::MAX` bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![Self::WIRE_FORMAT_VERSION];
+        self.encode(&mut bytes)
+            .expect("node path too long for wire format");
+
+        bytes
+    }
+
+    /// Decodes a path from the binary format produced by [`to_bytes()`][Self::to_bytes].
+    ///
+    /// `bytes` must contain exactly one encoded path; trailing data is rejected.
+    pub fn from_bytes(bytes: &[u8]) -> Result<NodePath, NodePathDecodeError> {
+        let (&version, rest) = bytes
+            .split_first()
+            .ok_or(NodePathDecodeError::TruncatedLength)?;
+
+        if version != Self::WIRE_FORMAT_VERSION {
+            return Err(NodePathDecodeError::UnsupportedVersion(version));
+        }
+
+        let (len, rest) = rest
+            .split_first_chunk::<4>()
+            .ok_or(NodePathDecodeError::TruncatedLength)?;
+        let len = u32::from_le_bytes(*len) as usize;
+
+        if rest.len() < len {
+            return Err(NodePathDecodeError::TruncatedData(len - rest.len()));
+        }
+
+        let (text, trailing) = rest.split_at(len);
+        if !trailing.is_empty() {
+            return Err(NodePathDecodeError::TrailingBytes(trailing.len()));
+        }
+
+        let text = std::str::from_utf8(text).map_err(|_| NodePathDecodeError::InvalidUtf8)?;
+        Ok(NodePath::from(text))
+    }
+
+    /// Resolves this path to a node in `tree`, using a cache of earlier resolutions.
+    ///
+    /// The path is looked up from the root of `tree`, like `get_node_or_null()` called on the root viewport. On success, the node's
+    /// [`InstanceId`] is cached. Subsequent calls with an equal path return the cached ID after only checking that the node is still alive,
+    /// without searching the tree. Returns `None` if no node exists at the path; such misses are not cached.
+    ///
+    /// # Cache
+    ///
+    /// The cache is opt-in, as only this method uses it. The first call for a given tree connects to its `node_removed` and
+    /// `node_renamed` signals, which keep the cache up to date:
+    /// - An entry is removed when its node leaves the tree, i.e. when it is freed, removed or moved elsewhere.
+    /// - All entries are removed when any node in the tree is renamed, as this changes the paths of the node's entire subtree.
+    ///
+    /// Apart from that, the cache is thread-local and grows with every distinct path that is resolved. Use
+    /// [`clear_resolve_cache()`][Self::clear_resolve_cache] to empty it, for example when changing scenes.
+    ///
+    /// The cache itself is never dropped: paths still cached when the thread ends are leaked, because the engine may have shut down by then
+    /// and can no longer free them. Call [`clear_resolve_cache()`][Self::clear_resolve_cache] before shutdown to avoid this.
+    #[cfg(since_api = "4.2")]
+    #[doc(alias = "get_node_or_null")]
+    pub fn resolve_cached(&self, tree: &Gd<SceneTree>) -> Option<InstanceId> {
+        resolve_cache::resolve(self, tree)
+    }
+
+    /// Removes all entries from the cache used by [`resolve_cached()`][Self::resolve_cached] on the current thread.
+    ///
+    /// Signals connected by `resolve_cached()` stay connected, so the cache remains up to date when it is filled again.
+    #[cfg(since_api = "4.2")]
+    pub fn clear_resolve_cache() {
+        resolve_cache::clear();
+    }
+
+    /// Returns a 32-bit integer hash value representing the string.
+    pub fn hash(&self) -> u32 {
+        self.as_inner()
+            .hash()
+            .try_into()
+            .expect("Godot hashes are uint32_t")
+    }
+
+    /// Feeds this path into `state`, by writing the value of [`hash()`][Self::hash].
+    ///
+    /// This is exactly what the [`Hash`][std::hash::Hash] impl does, made available under a name that does not clash with the inherent
+    /// `hash()`. Since Godot's hash depends only on the path text, the value written is the same across runs and platforms, so paths
+    /// can be combined with other data into deterministic composite keys (provided the hasher itself is deterministic).
+    pub fn hash_into<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.hash(), state)
+    }
+
+    /// Collects `paths` into a set, dropping paths that are equal to an earlier one.
+    ///
+    /// This is a plain `collect::<HashSet<_>>()`, but it documents what "equal" means: both [`Hash`][std::hash::Hash] and `Eq` are
+    /// delegated to Godot, which compares the parsed components rather than the text. As a consequence:
+    /// - Text that Godot parses to the same components is deduplicated, e.g. `"A/B"` and `"A//B/"` (empty names are skipped), as well
+    ///   as `"Node:prop"` and `"Node:prop:"`. Anything after a null byte is ignored as well.
+    /// - Paths are not simplified, so `"A/./B"` and `"A/B"` stay distinct; call [`simplified()`][Self::simplified] first if needed.
+    /// - Absolute and relative paths are distinct, e.g. `"/root"` and `"root"`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    ///
+    /// let unique = NodePath::collect_unique(["A/B", "A/B", "A/./B"].map(NodePath::from));
+    /// assert_eq!(unique.len(), 2);
+    /// ```
+    #[doc(alias = "hash_set_from_iter")]
+    pub fn collect_unique(paths: impl IntoIterator<Item = NodePath>) -> HashSet<NodePath> {
+        paths.into_iter().collect()
+    }
+
+    /// Feeds the full UTF-8 text of this path into `state`, instead of Godot's 32-bit hash.
+    ///
+    /// The [`Hash`][std::hash::Hash] impl and [`hash_into()`][Self::hash_into] only pass on 32 bits, so with a 64-bit hasher, distinct
+    /// paths still collide with a probability of about `n² / 2³³` among `n` paths -- roughly even odds at 77,000 paths. Hashing the
+    /// full text lets the hasher decide the quality, at the cost of converting the path to a Rust string on every call, which is
+    /// considerably slower than the cached Godot hash.
+    ///
+    /// Use this where collisions are costly, e.g. for fingerprints or content-addressed storage. For `HashMap` keys, the `Hash` impl
+    /// is usually the better choice, since equality resolves collisions anyway.
+    pub fn hash_bytes<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(self.to_string().as_bytes(), state)
+    }
+
+    /// Borrows a `NodePath` owned by someone else (e.g. Godot) through its sys pointer, without copying or touching the ref-count.
+    ///
+    /// This is meant for low-level FFI code that receives a `GDExtensionConstTypePtr` to a `NodePath` and only needs read access
+    /// for a limited scope. In contrast to [`GodotFfi::from_arg_ptr()`], no new `NodePath` is constructed, so nothing is released
+    /// on drop either.
+    ///
+    /// # Safety
+    /// - `ptr` must point to a valid, initialized `NodePath` (i.e. Godot's `NodePath` type, not any other builtin).
+    /// - That `NodePath` must stay alive and at the same address for the whole lifetime `'a`. Choose `'a` as short as possible; it is
+    ///   not tied to anything by this function.
+    /// - The `NodePath` must not be modified or destroyed (by Rust or Godot) while the returned reference exists.
+    pub unsafe fn borrow_from_sys<'a>(ptr: sys::GDExtensionConstTypePtr) -> &'a NodePath {
+        sys::static_assert_eq_size_align!(NodePath, sys::types::OpaqueNodePath);
+
+        // SAFETY: `NodePath` is `repr(transparent)` over its opaque storage, and the caller guarantees that `ptr` points to a live,
+        // unmodified `NodePath` for `'a`.
+        unsafe { &*(ptr.cast::<NodePath>()) }
+    }
+
+    #[doc(hidden)]
+    pub fn as_inner(&self) -> inner::InnerNodePath {
+        inner::InnerNodePath::from_outer(self)
+    }
+
+    /// All names (`/`-separated components) of this path.
+    fn name_vec(&self) -> Vec<StringName> {
+        let inner = self.as_inner();
+        (0..inner.get_name_count())
+            .map(|i| inner.get_name(i))
+            .collect()
+    }
+
+    /// All subnames (`:`-separated components) of this path.
+    fn subname_vec(&self) -> Vec<StringName> {
+        let inner = self.as_inner();
+        (0..inner.get_subname_count())
+            .map(|i| inner.get_subname(i))
+            .collect()
+    }
+
+    /// Assembles a path from its components, without checking that the components themselves are free of separators.
+    fn from_parts_unchecked(
+        is_absolute: bool,
+        names: &[StringName],
+        subnames: &[StringName],
+    ) -> NodePath {
+        NodePath::from(Self::parts_to_string(is_absolute, names, subnames))
+    }
+
+    /// Formats path components as a string, e.g. `/A/B:c:d`.
+    fn parts_to_string(is_absolute: bool, names: &[StringName], subnames: &[StringName]) -> String {
+        let mut path = String::new();
+        if is_absolute {
+            path.push('/');
+        }
+
+        for (i, name) in names.iter().enumerate() {
+            if i > 0 {
+                path.push('/');
+            }
+            path.push_str(&name.to_string());
+        }
+
+        for subname in subnames {
+            path.push(':');
+            path.push_str(&subname.to_string());
+        }
+
+        path
+    }
+}
+
+/// A single component of a [`NodePath`], as yielded by [`NodePath::iter_components()`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NodePathComponent {
+    /// A node name, separated by `/` in the path string.
+    Name(StringName),
+
+    /// A property or sub-resource name, separated by `:` in the path string.
+    Subname(StringName),
+}
+
+impl NodePathComponent {
+    /// Returns the name of this component, regardless of whether it is a name or subname.
+    pub fn as_string_name(&self) -> &StringName {
+        match self {
+            Self::Name(name) | Self::Subname(name) => name,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// NodePathComponents
+
+/// Names and subnames of a [`NodePath`], read once into Rust memory.
+///
+/// Created by [`NodePath::components()`]. Reading from the snapshot does not call into Godot, so it is suited for paths whose components
+/// are accessed repeatedly. The snapshot does not track later changes, since it holds its own copy of the components.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct NodePathComponents {
+    names: Vec<StringName>,
+    subnames: Vec<StringName>,
+    is_absolute: bool,
+}
+
+impl NodePathComponents {
+    /// The names (separated by `/`) of the path.
+    pub fn names(&self) -> &[StringName] {
+        &self.names
+    }
+
+    /// The subnames (separated by `:`) of the path.
+    pub fn subnames(&self) -> &[StringName] {
+        &self.subnames
+    }
+
+    /// Whether the path is absolute, i.e. starts with `/`.
+    pub fn is_absolute(&self) -> bool {
+        self.is_absolute
+    }
+
+    /// Returns an iterator over all names and subnames, in the same order as [`NodePath::iter_components()`].
+    pub fn iter(&self) -> impl Iterator<Item = NodePathComponent> + '_ {
+        let names = self.names.iter().cloned().map(NodePathComponent::Name);
+        let subnames = self
+            .subnames
+            .iter()
+            .cloned()
+            .map(NodePathComponent::Subname);
+
+        names.chain(subnames)
+    }
+
+    /// Assembles a `NodePath` from the components again.
+    pub fn to_node_path(&self) -> NodePath {
+        NodePath::from_parts_unchecked(self.is_absolute, &self.names, &self.subnames)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// NodePathVisitor
+
+/// Visitor over the components of a [`NodePath`], driven by [`NodePath::accept()`].
+///
+/// Names are visited before subnames. Each callback receives the index of the component among the names or subnames, respectively.
+pub trait NodePathVisitor {
+    /// Called for each node name, i.e. each component separated by `/`.
+    fn visit_name(&mut self, index: usize, name: &StringName);
+
+    /// Called for each subname, i.e. each component separated by `:`.
+    fn visit_subname(&mut self, index: usize, subname: &StringName);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// StrictNodePathBuilder
+
+/// Assembles a [`NodePath`] component by component, rejecting components that would not survive parsing as a single component.
+///
+/// Godot splits path text at `/` and `:` and ignores everything after a null byte. Pushing a string such as `"a/b"` as one name would
+/// thus silently produce two names. This builder checks each component when it is pushed, so that the built path has exactly the
+/// components that were pushed. Names and subnames are collected separately; all subnames are placed after all names.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::NodePath;
+/// use godot::builtin::strings::StrictNodePathBuilder;
+///
+/// let mut builder = StrictNodePathBuilder::new_absolute();
+/// builder.push_name("root").unwrap();
+/// builder.push_name("Main").unwrap();
+/// builder.push_subname("position").unwrap();
+/// assert!(builder.push_name("Player/Sprite").is_err());
+///
+/// assert_eq!(builder.build(), NodePath::from("/root/Main:position"));
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct StrictNodePathBuilder {
+    is_absolute: bool,
+    names: Vec<String>,
+    subnames: Vec<String>,
+}
+
+impl StrictNodePathBuilder {
+    /// Creates a builder for a relative path, without any components.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder for an absolute path, without any components.
+    pub fn new_absolute() -> Self {
+        Self {
+            is_absolute: true,
+            ..Self::default()
+        }
+    }
+
+    /// Appends a node name.
+    ///
+    /// # Errors
+    /// Returns [`ComponentError`] if `name` is empty or contains `/`, `:` or a null byte. The builder is unchanged in that case.
+    pub fn push_name(&mut self, name: &str) -> Result<(), ComponentError> {
+        validate_component(name)?;
+        self.names.push(name.to_string());
+        Ok(())
+    }
+
+    /// Appends a subname.
+    ///
+    /// # Errors
+    /// Returns [`ComponentError`] if `subname` is empty or contains `/`, `:` or a null byte. The builder is unchanged in that case.
+    pub fn push_subname(&mut self, subname: &str) -> Result<(), ComponentError> {
+        validate_component(subname)?;
+        self.subnames.push(subname.to_string());
+        Ok(())
+    }
+
+    /// Parses the collected components into a `NodePath`.
+    pub fn build(self) -> NodePath {
+        let mut path = String::new();
+        if self.is_absolute {
+            path.push('/');
+        }
+
+        path.push_str(&self.names.join("/"));
+        for subname in &self.subnames {
+            path.push(':');
+            path.push_str(subname);
+        }
+
+        NodePath::from(path)
+    }
+}
+
+fn validate_component(component: &str) -> Result<(), ComponentError> {
+    if component.is_empty() {
+        return Err(ComponentError::Empty);
+    }
+
+    match component.chars().find(|ch| matches!(ch, '/' | ':' | '\0')) {
+        Some(character) => Err(ComponentError::IllegalCharacter {
+            component: component.to_string(),
+            character,
+        }),
+        None => Ok(()),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// HashedNodePath
+
+/// A [`NodePath`] together with its precomputed Godot hash.
+///
+/// The `Hash` impl of `NodePath` calls into Godot every time a key is hashed, e.g. on each `HashMap` lookup and on every rehash when the
+/// map grows. `HashedNodePath` computes the hash once on construction, and feeds the cached value to the hasher afterwards. The result
+/// is identical to hashing the `NodePath` itself.
+///
+/// # Dereferencing
+///
+/// `HashedNodePath` implements `Deref<Target = NodePath>`, so all `NodePath` methods can be called on it directly, and `&HashedNodePath`
+/// coerces to `&NodePath` where the latter is expected. The other path wrappers ([`NodePathKey`], [`CaseInsensitiveNodePath`] and
+/// [`PropertyPath`]) do the same. Comparison and hashing of the wrapper itself are not affected by this; they use the wrapper's own impls.
+#[derive(Clone, Debug)]
+pub struct HashedNodePath {
+    path: NodePath,
+    hash: u32,
+}
+
+impl HashedNodePath {
+    /// Returns the Godot hash of the path, without an FFI call.
+    pub fn hash_value(&self) -> u32 {
+        self.hash
+    }
+
+    /// Returns the wrapped path.
+    pub fn into_inner(self) -> NodePath {
+        self.path
+    }
+}
+
+impl From<NodePath> for HashedNodePath {
+    fn from(path: NodePath) -> Self {
+        let hash = path.hash();
+        Self { path, hash }
+    }
+}
+
+impl std::ops::Deref for HashedNodePath {
+    type Target = NodePath;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl PartialEq for HashedNodePath {
+    fn eq(&self, other: &Self) -> bool {
+        // Different hashes imply different paths; avoids an FFI call in the common case.
+        self.hash == other.hash && self.path == other.path
+    }
+}
+
+impl Eq for HashedNodePath {}
+
+impl std::hash::Hash for HashedNodePath {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Must stay consistent with `Hash for NodePath`, which hashes the `u32` returned by `NodePath::hash()`.
+        self.hash.hash(state)
+    }
+}
+
+impl fmt::Display for HashedNodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.path, f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// NodePathKey
+
+/// A [`HashedNodePath`] together with its string form, for read-heavy caches.
+///
+/// Equality and hashing are those of `HashedNodePath`, so they are consistent with `NodePath` itself. In addition, the textual form is
+/// rendered once on construction, so `Display` and [`as_str()`][Self::as_str] need no conversion.
+///
+/// Dereferences to `NodePath`, see [`HashedNodePath`][HashedNodePath#dereferencing].
+#[derive(Clone, Debug)]
+pub struct NodePathKey {
+    key: HashedNodePath,
+    string: String,
+}
+
+impl NodePathKey {
+    /// Computes the hash and string form of `path` and stores them alongside it.
+    pub fn new(path: NodePath) -> Self {
+        let string = path.to_string();
+        Self {
+            key: HashedNodePath::from(path),
+            string,
+        }
+    }
+
+    /// Returns the Godot hash of the path, without an FFI call.
+    pub fn hash_value(&self) -> u32 {
+        self.key.hash_value()
+    }
+
+    /// Returns the cached string form of the path, as produced by `Display` for `NodePath`.
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Returns the wrapped path.
+    pub fn into_inner(self) -> NodePath {
+        self.key.into_inner()
+    }
+}
+
+impl From<NodePath> for NodePathKey {
+    fn from(path: NodePath) -> Self {
+        Self::new(path)
     }
+}
 
-    pub fn is_empty(&self) -> bool {
-        self.as_inner().is_empty()
+impl std::ops::Deref for NodePathKey {
+    type Target = NodePath;
+
+    fn deref(&self) -> &Self::Target {
+        &self.key
     }
+}
 
-    /// Returns a 32-bit integer hash value representing the string.
-    pub fn hash(&self) -> u32 {
-        self.as_inner()
-            .hash()
-            .try_into()
-            .expect("Godot hashes are uint32_t")
+impl PartialEq for NodePathKey {
+    fn eq(&self, other: &Self) -> bool {
+        // The string is derived from the path, so it need not be compared.
+        self.key == other.key
     }
+}
 
-    #[doc(hidden)]
-    pub fn as_inner(&self) -> inner::InnerNodePath {
-        inner::InnerNodePath::from_outer(self)
+impl Eq for NodePathKey {}
+
+impl std::hash::Hash for NodePathKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.key, state)
+    }
+}
+
+impl fmt::Display for NodePathKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.string)
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 // SAFETY:
 // - `move_return_ptr`
 //   Nothing special needs to be done beyond a `std::mem::swap` when returning a NodePath.
@@ -93,6 +1680,8 @@ impl fmt::Display for NodePath {
 }
 
 /// Uses literal syntax from GDScript: `^"node_path"`
+///
+/// The path text is not escaped; use [`NodePath::to_gdscript_literal()`] to generate GDScript source.
 impl fmt::Debug for NodePath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let string = GString::from(self);
@@ -112,6 +1701,10 @@ impl From<&str> for NodePath {
 }
 
 impl From<String> for NodePath {
+    /// Converts this `String` to a `NodePath`.
+    ///
+    /// Godot stores strings as UTF-32 and offers no way to take ownership of a Rust UTF-8 buffer. The owned `String` thus cannot be
+    /// reused, and this is identical to `NodePath::from(s.as_str())`.
     fn from(s: String) -> Self {
         GString::from(s).into()
     }
@@ -123,6 +1716,22 @@ impl From<&String> for NodePath {
     }
 }
 
+impl From<&[&str]> for NodePath {
+    /// Joins the names with `/` into a relative path, e.g. `["A", "B", "C"]` becomes `"A/B/C"`.
+    ///
+    /// The names are not validated; a name containing `/` or `:` is parsed as multiple components. An empty slice yields an empty path.
+    fn from(names: &[&str]) -> Self {
+        NodePath::from(names.join("/"))
+    }
+}
+
+impl From<Vec<String>> for NodePath {
+    /// Joins the names with `/` into a relative path. See `From<&[&str]>` for details.
+    fn from(names: Vec<String>) -> Self {
+        NodePath::from(names.join("/"))
+    }
+}
+
 impl From<&GString> for NodePath {
     fn from(string: &GString) -> Self {
         unsafe {
@@ -159,13 +1768,456 @@ impl From<StringName> for NodePath {
     }
 }
 
+/// Converts a variant holding any string-like type into a `NodePath`.
+///
+/// Accepts variants of type [`NODE_PATH`][VariantType::NODE_PATH], [`STRING`][VariantType::STRING] and
+/// [`STRING_NAME`][VariantType::STRING_NAME], which GDScript uses interchangeably for paths, e.g. in exported properties.
+/// Returns `None` for all other types, including `nil`.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::strings::coerce_to_node_path;
+/// use godot::builtin::{GString, NodePath, Variant};
+/// use godot::meta::ToGodot;
+///
+/// let from_string = GString::from("Player/Sprite").to_variant();
+/// assert_eq!(coerce_to_node_path(&from_string), Some(NodePath::from("Player/Sprite")));
+///
+/// assert_eq!(coerce_to_node_path(&42.to_variant()), None);
+/// ```
+pub fn coerce_to_node_path(variant: &Variant) -> Option<NodePath> {
+    match variant.get_type() {
+        VariantType::NODE_PATH => Some(variant.to::<NodePath>()),
+        VariantType::STRING => Some(NodePath::from(variant.to::<GString>())),
+        VariantType::STRING_NAME => Some(NodePath::from(variant.to::<StringName>())),
+        _ => None,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// CaseInsensitiveNodePath
+
+/// A [`NodePath`] that compares and hashes case-insensitively.
+///
+/// `"Root/Player:Position"` and `"root/player:position"` are equal under this wrapper and map to the same `HashMap` bucket. Equality
+/// still distinguishes absolute from relative paths, and names from subnames.
+///
+/// Case is normalized to Unicode lowercase per Rust's [`str::to_lowercase()`], not just ASCII. This may differ from Godot's `to_lower()`
+/// for some characters, such as a final sigma. It is not full case folding either, so e.g. `"ß"` and `"SS"` remain different. The
+/// normalized text is computed once on construction.
+///
+/// Dereferences to `NodePath`, see [`HashedNodePath`][HashedNodePath#dereferencing].
+#[derive(Clone, Debug)]
+pub struct CaseInsensitiveNodePath {
+    path: NodePath,
+    lowercase: String,
+}
+
+impl CaseInsensitiveNodePath {
+    /// Returns the wrapped path, with its original case.
+    pub fn into_inner(self) -> NodePath {
+        self.path
+    }
+}
+
+impl From<NodePath> for CaseInsensitiveNodePath {
+    fn from(path: NodePath) -> Self {
+        let lowercase = path.to_string().to_lowercase();
+        Self { path, lowercase }
+    }
+}
+
+impl std::ops::Deref for CaseInsensitiveNodePath {
+    type Target = NodePath;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl PartialEq for CaseInsensitiveNodePath {
+    fn eq(&self, other: &Self) -> bool {
+        // Separators `/` and `:` are unaffected by lowercasing, so comparing the full text also compares the structure.
+        self.lowercase == other.lowercase
+    }
+}
+
+impl Eq for CaseInsensitiveNodePath {}
+
+impl std::hash::Hash for CaseInsensitiveNodePath {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.lowercase.hash(state)
+    }
+}
+
+impl fmt::Display for CaseInsensitiveNodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.path, f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// PropertyPath
+
+/// A [`NodePath`] that is statically known to address a property, i.e. it has at least one subname.
+///
+/// Use this type in APIs that resolve paths with `Object::get_indexed()` or similar, to rule out plain node paths at compile time.
+/// Construct it with `TryFrom<NodePath>`, which fails for paths without subnames, or with [`NodePath::to_property_path()`].
+///
+/// Dereferences to `NodePath`, see [`HashedNodePath`][HashedNodePath#dereferencing].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PropertyPath {
+    path: NodePath,
+}
+
+impl PropertyPath {
+    /// Returns the wrapped path.
+    pub fn into_inner(self) -> NodePath {
+        self.path
+    }
+}
+
+impl TryFrom<NodePath> for PropertyPath {
+    type Error = NodePathError;
+
+    fn try_from(path: NodePath) -> Result<Self, Self::Error> {
+        if path.is_property_path() {
+            Ok(Self { path })
+        } else {
+            Err(NodePathError::NoSubnames)
+        }
+    }
+}
+
+impl From<PropertyPath> for NodePath {
+    fn from(path: PropertyPath) -> Self {
+        path.path
+    }
+}
+
+impl std::ops::Deref for PropertyPath {
+    type Target = NodePath;
+
+    fn deref(&self) -> &Self::Target {
+        &self.path
+    }
+}
+
+impl fmt::Display for PropertyPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.path, f)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Resolve cache
+
+#[cfg(since_api = "4.2")]
+mod resolve_cache {
+    use std::cell::RefCell;
+    use std::collections::{HashMap, HashSet};
+    use std::mem::ManuallyDrop;
+
+    use crate::builtin::{Callable, NodePath, Variant};
+    use crate::classes::{Node, SceneTree};
+    use crate::obj::{Gd, InstanceId};
+
+    /// Paths resolved by [`NodePath::resolve_cached()`], invalidated through signals of the scene tree.
+    #[derive(Default)]
+    struct ResolveCache {
+        /// Node that each path resolves to.
+        nodes: HashMap<NodePath, InstanceId>,
+
+        /// Paths cached for each node, so they can be removed once the node leaves the tree.
+        paths: HashMap<InstanceId, Vec<NodePath>>,
+
+        /// Scene trees whose signals are connected to the cache. Not cleared, as the connections persist.
+        trees: HashSet<InstanceId>,
+    }
+
+    impl ResolveCache {
+        fn forget(&mut self, instance_id: InstanceId) {
+            for path in self.paths.remove(&instance_id).unwrap_or_default() {
+                self.nodes.remove(&path);
+            }
+        }
+
+        fn clear(&mut self) {
+            self.nodes.clear();
+            self.paths.clear();
+        }
+    }
+
+    thread_local! {
+        // ManuallyDrop: thread-locals may be destroyed after the engine has shut down, when paths can no longer be freed.
+        static RESOLVE_CACHE: RefCell<ManuallyDrop<ResolveCache>> = RefCell::default();
+    }
+
+    pub(super) fn resolve(path: &NodePath, tree: &Gd<SceneTree>) -> Option<InstanceId> {
+        let cached = RESOLVE_CACHE.with_borrow(|cache| cache.nodes.get(path).copied());
+        if let Some(instance_id) = cached {
+            // Entries are removed once their node leaves the tree or a node is renamed, so a live node is still at the path.
+            if Gd::<Node>::try_from_instance_id(instance_id).is_ok() {
+                return Some(instance_id);
+            }
+
+            RESOLVE_CACHE.with_borrow_mut(|cache| cache.forget(instance_id));
+        }
+
+        let node = tree.get_root()?.get_node_or_null(path.clone())?;
+        let instance_id = node.instance_id();
+        connect_signals(tree);
+
+        RESOLVE_CACHE.with_borrow_mut(|cache| {
+            cache.nodes.insert(path.clone(), instance_id);
+            cache
+                .paths
+                .entry(instance_id)
+                .or_default()
+                .push(path.clone());
+        });
+
+        Some(instance_id)
+    }
+
+    pub(super) fn clear() {
+        RESOLVE_CACHE.with_borrow_mut(|cache| cache.clear());
+    }
+
+    fn connect_signals(tree: &Gd<SceneTree>) {
+        let is_new = RESOLVE_CACHE.with_borrow_mut(|cache| cache.trees.insert(tree.instance_id()));
+        if !is_new {
+            return;
+        }
+
+        // Connected outside of any borrow: the engine may emit signals at any point during the calls.
+        let mut tree = tree.clone();
+        tree.connect(
+            "node_removed".into(),
+            Callable::from_fn("NodePath::resolve_cached", on_node_removed),
+        );
+        tree.connect(
+            "node_renamed".into(),
+            Callable::from_fn("NodePath::resolve_cached", on_node_renamed),
+        );
+    }
+
+    /// Emitted for every node of a subtree that leaves the tree, including before the node is freed.
+    fn on_node_removed(args: &[&Variant]) -> Result<Variant, ()> {
+        let node = args
+            .first()
+            .ok_or(())?
+            .try_to::<Gd<Node>>()
+            .map_err(|_| ())?;
+        RESOLVE_CACHE.with_borrow_mut(|cache| cache.forget(node.instance_id()));
+
+        Ok(Variant::nil())
+    }
+
+    /// Emitted only for the renamed node, although the paths of its descendants change as well.
+    fn on_node_renamed(_args: &[&Variant]) -> Result<Variant, ()> {
+        RESOLVE_CACHE.with_borrow_mut(|cache| cache.clear());
+
+        Ok(Variant::nil())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Validation
+
+fn validate_path(path: &str) -> Result<(), NodePathError> {
+    let (names, subnames) = match path.split_once(':') {
+        Some((names, subnames)) => (names, Some(subnames)),
+        None => (path, None),
+    };
+
+    // Absolute path: leading `/` is not a separator. The root path "/" on its own has no names.
+    let names = names.strip_prefix('/').unwrap_or(names);
+
+    if !names.is_empty() {
+        for (index, name) in names.split('/').enumerate() {
+            if name.is_empty() {
+                return Err(NodePathError::EmptyName { index });
+            }
+
+            validate_name(name)?;
+        }
+    }
+
+    if let Some(subnames) = subnames {
+        for (index, subname) in subnames.split(':').enumerate() {
+            if subname.is_empty() {
+                return Err(NodePathError::EmptySubname { index });
+            }
+
+            // Subnames are property names, which may contain `/` (e.g. `shader_parameter/albedo`) and `.`.
+            validate_characters(subname, |ch| ch.is_control() || ch == '"')?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_name(name: &str) -> Result<(), NodePathError> {
+    if name == "." || name == ".." {
+        return Ok(());
+    }
+
+    // Leading `%` marks a scene-unique name.
+    let unmarked = name.strip_prefix('%').unwrap_or(name);
+
+    validate_characters(unmarked, |ch| {
+        ch.is_control() || matches!(ch, '.' | '"' | '%')
+    })
+    .map_err(|err| match err {
+        NodePathError::IllegalCharacter { character, .. } => NodePathError::IllegalCharacter {
+            component: name.to_string(),
+            character,
+        },
+        other => other,
+    })
+}
+
+fn validate_characters(
+    component: &str,
+    is_illegal: impl Fn(char) -> bool,
+) -> Result<(), NodePathError> {
+    match component.chars().find(|&ch| is_illegal(ch)) {
+        Some(character) => Err(NodePathError::IllegalCharacter {
+            component: component.to_string(),
+            character,
+        }),
+        None => Ok(()),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Glob matching
+
+fn split_glob_pattern(pattern: &str, separator: char) -> Vec<&str> {
+    if pattern.is_empty() {
+        Vec::new()
+    } else {
+        pattern.split(separator).collect()
+    }
+}
+
+/// Matches path components against pattern components, where `*` matches one and `**` matches zero or more components.
+fn glob_match(pattern: &[&str], components: &[String]) -> bool {
+    match pattern.split_first() {
+        None => components.is_empty(),
+        Some((&"**", rest)) => {
+            (0..=components.len()).any(|skipped| glob_match(rest, &components[skipped..]))
+        }
+        Some((&expected, rest)) => match components.split_first() {
+            Some((component, remaining)) => {
+                (expected == "*" || expected == component) && glob_match(rest, remaining)
+            }
+            None => false,
+        },
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Natural ordering
+
+fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let mut a_rest = a;
+    let mut b_rest = b;
+    loop {
+        match (split_natural_run(a_rest), split_natural_run(b_rest)) {
+            // All runs equal; texts may still differ in leading zeros.
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some((a_run, a_tail)), Some((b_run, b_tail))) => {
+                let ordering = natural_cmp_runs(a_run, b_run);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+
+                a_rest = a_tail;
+                b_rest = b_tail;
+            }
+        }
+    }
+}
+
+/// Splits off the leading run of either ASCII digits or other characters, or returns `None` if `text` is empty.
+fn split_natural_run(text: &str) -> Option<(&str, &str)> {
+    let is_digit = text.chars().next()?.is_ascii_digit();
+    let end = text
+        .find(|ch: char| ch.is_ascii_digit() != is_digit)
+        .unwrap_or(text.len());
+
+    Some(text.split_at(end))
+}
+
+/// Compares digit runs numerically (without overflow, for any number of digits), and all other runs lexically.
+fn natural_cmp_runs(a: &str, b: &str) -> std::cmp::Ordering {
+    let is_number = |run: &str| run.starts_with(|ch: char| ch.is_ascii_digit());
+    if !is_number(a) || !is_number(b) {
+        return a.cmp(b);
+    }
+
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// camino support
+
+#[cfg(feature = "camino")]
+mod camino_conv {
+    use super::*;
+    use camino::{Utf8Path, Utf8PathBuf};
+
+    impl NodePath {
+        /// Converts this path to a [`Utf8PathBuf`], with `/` as separator.
+        ///
+        /// The text is taken over verbatim, including any subnames (e.g. `"A/B:prop"`). The result uses `/` on all platforms, which
+        /// Windows accepts as well. An absolute node path becomes a path starting at the filesystem root.
+        pub fn to_utf8_path_buf(&self) -> Utf8PathBuf {
+            Utf8PathBuf::from(self.to_string())
+        }
+    }
+
+    impl From<&Utf8Path> for NodePath {
+        /// Parses a `NodePath` from a UTF-8 filesystem path, converting platform-specific separators to `/`.
+        ///
+        /// The text is otherwise taken over verbatim; in particular, a `:` starts a subname, as with any string conversion.
+        fn from(path: &Utf8Path) -> Self {
+            let path = path.as_str();
+
+            if std::path::MAIN_SEPARATOR == '/' {
+                NodePath::from(path)
+            } else {
+                NodePath::from(path.replace(std::path::MAIN_SEPARATOR, "/"))
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// serde support
+
+#[cfg(feature = "serde")]
+pub use serialize::{deserialize_node_path_seq, serde_empty_as_none};
+
 #[cfg(feature = "serde")]
 mod serialize {
     use super::*;
-    use serde::de::{Error, Visitor};
+    use serde::de::{Error, SeqAccess, Visitor};
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use std::fmt::Formatter;
 
+    // Human-readable formats (e.g. JSON) store the path as a string. Compact formats (e.g. bincode) store the UTF-8 bytes of the same
+    // string, which lets the format use its native length-prefixed byte encoding.
+
     // For "Available on crate feature `serde`" in docs. Cannot be inherited from module. Also does not support #[derive] (e.g. in Vector2).
     #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
     impl Serialize for NodePath {
@@ -177,7 +2229,25 @@ mod serialize {
         where
             S: Serializer,
         {
-            serializer.serialize_newtype_struct("NodePath", &*self.to_string())
+            let string = self.to_string();
+
+            if serializer.is_human_readable() {
+                serializer.serialize_newtype_struct("NodePath", &*string)
+            } else {
+                serializer.serialize_newtype_struct("NodePath", &Bytes(string.as_bytes()))
+            }
+        }
+    }
+
+    /// Serializes as a byte array rather than a sequence of `u8`.
+    struct Bytes<'a>(&'a [u8]);
+
+    impl Serialize for Bytes<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_bytes(self.0)
         }
     }
 
@@ -204,6 +2274,14 @@ mod serialize {
                     Ok(NodePath::from(s))
                 }
 
+                fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+                where
+                    E: Error,
+                {
+                    let s = std::str::from_utf8(bytes).map_err(E::custom)?;
+                    Ok(NodePath::from(s))
+                }
+
                 fn visit_newtype_struct<D>(
                     self,
                     deserializer: D,
@@ -211,11 +2289,109 @@ mod serialize {
                 where
                     D: Deserializer<'de>,
                 {
-                    deserializer.deserialize_str(self)
+                    if deserializer.is_human_readable() {
+                        deserializer.deserialize_str(self)
+                    } else {
+                        deserializer.deserialize_bytes(self)
+                    }
                 }
             }
 
             deserializer.deserialize_newtype_struct("NodePath", NodePathVisitor)
         }
     }
+
+    /// Deserializes a sequence of node paths, passing each path to `on_path` as soon as it is parsed.
+    ///
+    /// In contrast to deserializing a `Vec<NodePath>`, the paths are never collected, so memory usage does not grow with the length of
+    /// the sequence. Elements are deserialized with the regular [`Deserialize`] impl of `NodePath`.
+    ///
+    /// Since `on_path` is an extra parameter, this function cannot be passed to `#[serde(deserialize_with)]` directly. Instead, call it from
+    /// a wrapper that captures the callback, or from a custom `Deserialize` impl of the containing type.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::strings::deserialize_node_path_seq;
+    ///
+    /// let json = r#"["/root/A", "/root/B"]"#;
+    /// let mut deserializer = serde_json::Deserializer::from_str(json);
+    ///
+    /// let mut count = 0;
+    /// deserialize_node_path_seq(&mut deserializer, |_path| count += 1).unwrap();
+    /// assert_eq!(count, 2);
+    /// ```
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    pub fn deserialize_node_path_seq<'de, D, F>(deserializer: D, on_path: F) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+        F: FnMut(NodePath),
+    {
+        struct NodePathSeqVisitor<F> {
+            on_path: F,
+        }
+
+        impl<'de, F> Visitor<'de> for NodePathSeqVisitor<F>
+        where
+            F: FnMut(NodePath),
+        {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a sequence of NodePaths")
+            }
+
+            fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                while let Some(path) = seq.next_element::<NodePath>()? {
+                    (self.on_path)(path);
+                }
+
+                Ok(())
+            }
+        }
+
+        deserializer.deserialize_seq(NodePathSeqVisitor { on_path })
+    }
+
+    /// Serde adapter for `Option<NodePath>` fields, treating an empty path the same as `None`.
+    ///
+    /// Godot commonly uses an empty `NodePath` to mean "no path". With this adapter, both `None` and `Some(NodePath::default())` are
+    /// serialized as `none` (`null` in JSON). When deserializing, `none` as well as an empty path yield `None`, so the two representations
+    /// never leak into the Rust side.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use godot::builtin::NodePath;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct SaveData {
+    ///     #[serde(with = "godot::builtin::strings::serde_empty_as_none")]
+    ///     target: Option<NodePath>,
+    /// }
+    /// ```
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    pub mod serde_empty_as_none {
+        use super::*;
+
+        pub fn serialize<S>(value: &Option<NodePath>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match value {
+                Some(path) if !path.is_empty() => serializer.serialize_some(path),
+                _ => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NodePath>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let path = Option::<NodePath>::deserialize(deserializer)?;
+            Ok(path.filter(|path| !path.is_empty()))
+        }
+    }
 }
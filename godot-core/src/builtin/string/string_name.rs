@@ -55,6 +55,51 @@ impl StringName {
         Self { opaque }
     }
 
+    /// Creates a `StringName` from an ASCII string, skipping UTF-8 decoding.
+    ///
+    /// Godot reads the bytes as Latin-1, which is cheaper than decoding UTF-8 and gives the same result for ASCII input. This is meant
+    /// for method, signal and property names in registration code, which are created in large numbers at startup. Like other
+    /// constructors, anything after a null byte is ignored.
+    ///
+    /// Non-ASCII input is a logic error: in Debug mode it panics, in Release mode each UTF-8 byte becomes its own Latin-1 character.
+    /// Use `StringName::from(&str)` for arbitrary text.
+    ///
+    /// # Panics
+    /// In Debug mode, if `ascii` contains non-ASCII characters.
+    pub fn from_ascii(ascii: &str) -> Self {
+        debug_assert!(
+            ascii.is_ascii(),
+            "StringName::from_ascii() called with non-ASCII string {ascii:?}"
+        );
+
+        Self::from_ascii_unchecked(ascii)
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn from_ascii_unchecked(ascii: &str) -> Self {
+        // The Latin-1 constructor expects a null-terminated buffer.
+        let mut bytes = Vec::with_capacity(ascii.len() + 1);
+        bytes.extend_from_slice(ascii.as_bytes());
+        bytes.push(0);
+
+        // SAFETY: `bytes` is null-terminated and outlives the call. Since it is not static, Godot copies the contents.
+        unsafe {
+            Self::new_with_string_uninit(|ptr| {
+                sys::interface_fn!(string_name_new_with_latin1_chars)(
+                    ptr,
+                    bytes.as_ptr() as *const std::ffi::c_char,
+                    sys::conv::SYS_FALSE, // p_is_static
+                )
+            })
+        }
+    }
+
+    #[cfg(before_api = "4.2")]
+    fn from_ascii_unchecked(ascii: &str) -> Self {
+        // No Latin-1 constructor for `StringName` before Godot 4.2.
+        Self::from(ascii)
+    }
+
     /// Returns the number of characters in the string.
     ///
     /// _Godot equivalent: `length`_
@@ -0,0 +1,128 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasherDefault, Hasher};
+
+use super::StringName;
+
+/// Hash map with [`StringName`] keys, hashed with Godot's own string hash.
+///
+/// Intended for name-based dispatch (methods, properties, signals), where a large `match` on strings would otherwise be needed.
+/// Keys are stored as `StringName`, so lookups with an existing `StringName` do not intern the name again.
+///
+/// The hash of a key is the value returned by [`StringName::hash()`], which Godot caches inside the `StringName`. Unlike with
+/// `HashMap<StringName, V>` and the default SipHash hasher, that value is used directly instead of being hashed a second time.
+///
+/// Iteration order is unspecified.
+pub struct StringNameMap<V> {
+    map: HashMap<StringName, V, BuildHasherDefault<GodotHasher>>,
+}
+
+impl<V> StringNameMap<V> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::default(),
+        }
+    }
+
+    /// Inserts a value under `key`, returning the previous value for that key, if any.
+    pub fn insert(&mut self, key: StringName, value: V) -> Option<V> {
+        self.map.insert(key, value)
+    }
+
+    /// Returns a reference to the value stored under `key`, or `None` if there is none.
+    pub fn get(&self, key: &StringName) -> Option<&V> {
+        self.map.get(key)
+    }
+
+    /// Returns a mutable reference to the value stored under `key`, or `None` if there is none.
+    pub fn get_mut(&mut self, key: &StringName) -> Option<&mut V> {
+        self.map.get_mut(key)
+    }
+
+    /// Returns `true` if the map contains a value for `key`.
+    pub fn contains_key(&self, key: &StringName) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Removes the value stored under `key` and returns it, or `None` if there is none.
+    pub fn remove(&mut self, key: &StringName) -> Option<V> {
+        self.map.remove(key)
+    }
+
+    /// Number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over all entries, in unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = (&StringName, &V)> + '_ {
+        self.map.iter()
+    }
+}
+
+impl<V> Default for StringNameMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Clone> Clone for StringNameMap<V> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for StringNameMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.map.iter()).finish()
+    }
+}
+
+impl<V> FromIterator<(StringName, V)> for StringNameMap<V> {
+    fn from_iter<I: IntoIterator<Item = (StringName, V)>>(iter: I) -> Self {
+        Self {
+            map: iter.into_iter().collect(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Hasher that passes through the `u32` Godot hash written by `Hash for StringName`.
+#[derive(Default)]
+struct GodotHasher {
+    hash: u64,
+}
+
+impl Hasher for GodotHasher {
+    fn finish(&self) -> u64 {
+        // Spread the 32-bit hash over all 64 bits; `HashMap` uses the high bits for probing.
+        self.hash.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.hash = (self.hash << 32) ^ u64::from(value);
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        // Not used by `StringName`, but must be supported by any hasher. FNV-1a.
+        for &byte in bytes {
+            self.hash = (self.hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+}
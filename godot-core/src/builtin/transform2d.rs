@@ -301,10 +301,33 @@ impl Transform2D {
         Self::from_cols(self.a, self.b, self.origin + (self.to_basis() * offset))
     }
 
+    /// Returns a point transformed by this transform, including translation by the origin.
+    ///
+    /// This is the same as `self * p`. Use it for positions; for directions, velocities or offsets, which must not be translated,
+    /// use [`basis_xform()`][Self::basis_xform] instead.
+    ///
+    /// _Godot equivalent: `Transform2D * Vector2`_
+    #[doc(alias = "xform")]
+    pub fn xform_point(&self, p: Vector2) -> Vector2 {
+        *self * p
+    }
+
+    /// Returns a point transformed by the inverse of this transform, i.e. converted from the parent frame into this local frame.
+    ///
+    /// Unlike Godot's `Vector2 * Transform2D`, this is correct for transforms that are not orthonormal, e.g. with scaling or skew.
+    /// The counterpart for directions is [`basis_xform_inv()`][Self::basis_xform_inv].
+    #[doc(alias = "xform_inv")]
+    pub fn xform_point_inv(&self, p: Vector2) -> Vector2 {
+        self.basis_xform_inv(p - self.origin)
+    }
+
     /// Returns a vector transformed (multiplied) by the basis matrix.
     /// This method does not account for translation (the origin vector).
     ///
+    /// Use it for directions, velocities or offsets; for positions, use [`xform_point()`][Self::xform_point] instead.
+    ///
     /// _Godot equivalent: `Transform2D.basis_xform()`_
+    #[doc(alias = "xform_direction")]
     pub fn basis_xform(&self, v: Vector2) -> Vector2 {
         self.to_basis() * v
     }
@@ -685,6 +708,38 @@ mod test {
         assert_eq!(DUMMY_TRANSFORM.rotated_local(phi), DUMMY_TRANSFORM * r);
     }
 
+    #[test]
+    fn xform_point_and_direction() {
+        let trans =
+            Transform2D::from_angle_origin(real!(90.0).to_radians(), Vector2::new(10.0, 0.0));
+        let v = Vector2::new(1.0, 0.0);
+
+        // Points are rotated, then translated.
+        assert_eq_approx!(trans.xform_point(v), Vector2::new(10.0, 1.0));
+        assert_eq_approx!(trans.xform_point(v), trans * v);
+
+        // Directions are only rotated; the origin is not applied.
+        assert_eq_approx!(trans.basis_xform(v), Vector2::new(0.0, 1.0));
+
+        // Pure translation moves points, but leaves directions untouched.
+        let shift = Transform2D::IDENTITY.translated(Vector2::new(3.0, -4.0));
+        assert_eq!(shift.xform_point(v), Vector2::new(4.0, -4.0));
+        assert_eq!(shift.basis_xform(v), v);
+    }
+
+    #[test]
+    fn xform_point_inv() {
+        let point = Vector2::new(-2.5, 7.0);
+
+        // Non-orthonormal transform, to make sure the full inverse is used.
+        let transformed = DUMMY_TRANSFORM.xform_point(point);
+        assert_eq_approx!(DUMMY_TRANSFORM.xform_point_inv(transformed), point);
+        assert_eq_approx!(
+            DUMMY_TRANSFORM.xform_point_inv(point),
+            DUMMY_TRANSFORM.affine_inverse() * point
+        );
+    }
+
     #[test]
     fn interpolation() {
         let rotate_scale_skew_pos: Transform2D = Transform2D::from_angle_scale_skew_origin(
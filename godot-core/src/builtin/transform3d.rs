@@ -356,6 +356,7 @@ impl Mul<Plane> for Transform3D {
 
 impl ApproxEq for Transform3D {
     /// Returns if the two transforms are approximately equal, by comparing `basis` and `origin` separately.
+    #[doc(alias = "is_equal_approx")]
     fn approx_eq(&self, other: &Self) -> bool {
         Basis::approx_eq(&self.basis, &other.basis)
             && Vector3::approx_eq(&self.origin, &other.origin)
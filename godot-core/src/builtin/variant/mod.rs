@@ -68,6 +68,29 @@ impl Variant {
         T::try_from_variant(self)
     }
 
+    /// Create a variant from an optional value: `None` becomes nil, `Some(value)` becomes `value.to_variant()`.
+    ///
+    /// `Option<T>` itself implements [`ToGodot`] only for nullable types such as `Gd<T>`, since its Godot representation must be able to
+    /// hold `null`. This method works for any `T`.
+    pub fn from_option<T: ToGodot>(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.to_variant(),
+            None => Variant::nil(),
+        }
+    }
+
+    /// Convert to an optional `T`: nil becomes `Ok(None)`, any other value is converted to `T`.
+    ///
+    /// Counterpart to [`from_option()`][Self::from_option], for types `T` where `Option<T>` does not implement [`FromGodot`].
+    /// Returns `Err` if the variant is not nil and cannot be converted to `T`.
+    pub fn try_to_option<T: FromGodot>(&self) -> Result<Option<T>, ConvertError> {
+        if self.is_nil() {
+            Ok(None)
+        } else {
+            T::try_from_variant(self).map(Some)
+        }
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
     /// See also [`Self::get_type`].
@@ -81,6 +104,21 @@ impl Variant {
     /// If this variant holds a type `Object` but no instance (represented as a null object pointer), then `Nil` will be returned for
     /// consistency. This may deviate from Godot behavior -- for example, calling `Node::get_node_or_null()` with an invalid
     /// path returns a variant that has type `Object` but acts like `Nil` for all practical purposes.
+    ///
+    /// [`VariantType`] is not a Rust `enum`, but its constants can be used as `match` patterns. Since Godot may add new types, a
+    /// wildcard arm is always required:
+    /// ```no_run
+    /// use godot::builtin::{Variant, VariantType};
+    ///
+    /// fn describe(variant: &Variant) -> &'static str {
+    ///     match variant.get_type() {
+    ///         VariantType::NIL => "nothing",
+    ///         VariantType::BOOL | VariantType::INT | VariantType::FLOAT => "scalar",
+    ///         VariantType::STRING | VariantType::STRING_NAME => "text",
+    ///         _ => "something else",
+    ///     }
+    /// }
+    /// ```
     pub fn get_type(&self) -> VariantType {
         let sys_type = self.sys_type();
 
@@ -106,6 +144,60 @@ impl Variant {
         }
     }
 
+    /// Returns a human-readable name of the type held by this variant, e.g. `"Vector2"` or `"Dictionary"`.
+    ///
+    /// Names follow Godot's spelling (`"Nil"`, `"int"`, `"String"`, ...), as also used in GDScript and `type_string()`. Like
+    /// [`get_type()`][Self::get_type], a null object is reported as `"Nil"`.
+    ///
+    /// _Godot equivalent: `type_string(typeof(variant))`_
+    pub fn type_name(&self) -> &'static str {
+        match self.get_type() {
+            VariantType::NIL => "Nil",
+            VariantType::BOOL => "bool",
+            VariantType::INT => "int",
+            VariantType::FLOAT => "float",
+            VariantType::STRING => "String",
+            VariantType::VECTOR2 => "Vector2",
+            VariantType::VECTOR2I => "Vector2i",
+            VariantType::RECT2 => "Rect2",
+            VariantType::RECT2I => "Rect2i",
+            VariantType::VECTOR3 => "Vector3",
+            VariantType::VECTOR3I => "Vector3i",
+            VariantType::TRANSFORM2D => "Transform2D",
+            VariantType::VECTOR4 => "Vector4",
+            VariantType::VECTOR4I => "Vector4i",
+            VariantType::PLANE => "Plane",
+            VariantType::QUATERNION => "Quaternion",
+            VariantType::AABB => "AABB",
+            VariantType::BASIS => "Basis",
+            VariantType::TRANSFORM3D => "Transform3D",
+            VariantType::PROJECTION => "Projection",
+            VariantType::COLOR => "Color",
+            VariantType::STRING_NAME => "StringName",
+            VariantType::NODE_PATH => "NodePath",
+            VariantType::RID => "RID",
+            VariantType::OBJECT => "Object",
+            VariantType::CALLABLE => "Callable",
+            VariantType::SIGNAL => "Signal",
+            VariantType::DICTIONARY => "Dictionary",
+            VariantType::ARRAY => "Array",
+            VariantType::PACKED_BYTE_ARRAY => "PackedByteArray",
+            VariantType::PACKED_INT32_ARRAY => "PackedInt32Array",
+            VariantType::PACKED_INT64_ARRAY => "PackedInt64Array",
+            VariantType::PACKED_FLOAT32_ARRAY => "PackedFloat32Array",
+            VariantType::PACKED_FLOAT64_ARRAY => "PackedFloat64Array",
+            VariantType::PACKED_STRING_ARRAY => "PackedStringArray",
+            VariantType::PACKED_VECTOR2_ARRAY => "PackedVector2Array",
+            VariantType::PACKED_VECTOR3_ARRAY => "PackedVector3Array",
+            VariantType::PACKED_COLOR_ARRAY => "PackedColorArray",
+            #[cfg(since_api = "4.3")]
+            VariantType::PACKED_VECTOR4_ARRAY => "PackedVector4Array",
+
+            // Types added in future Godot versions.
+            _ => "<unknown>",
+        }
+    }
+
     /// ⚠️ Calls the specified `method` with the given `args`.
     ///
     /// Supports `Object` as well as built-ins with methods (e.g. `Array`, `Vector3`, `GString`, etc.).
@@ -195,11 +287,60 @@ impl Variant {
 
     /// Return Godot's hash value for the variant.
     ///
+    /// The value always fits into a `u32`; [`hash_u32()`][Self::hash_u32] returns it as such, and describes which variants hash equally.
+    ///
     /// _Godot equivalent : `@GlobalScope.hash()`_
     pub fn hash(&self) -> i64 {
         unsafe { interface_fn!(variant_hash)(self.var_sys()) }
     }
 
+    /// Return Godot's hash value for the variant, as the `u32` that Godot computes.
+    ///
+    /// Equal values of the same type have equal hashes, which makes this suitable for deduplicating values of mixed types, e.g. as part
+    /// of a `HashSet` key. Values of different types usually hash differently, even if Godot considers them equal: `1` and `1.0` have
+    /// different hashes.
+    ///
+    /// Every variant can be hashed without error, but not every hash is meaningful: `NIL` hashes to `0`, and objects hash by identity
+    /// rather than by content. As with any hash, different values may collide, so compare the variants themselves to confirm equality.
+    pub fn hash_u32(&self) -> u32 {
+        self.hash().try_into().expect("Godot hashes are uint32_t")
+    }
+
+    /// Returns a deep copy of the value held by this variant.
+    ///
+    /// Arrays, dictionaries and packed arrays are copied, including all arrays and dictionaries nested in them, so modifying the copy
+    /// at any level does not affect the original. Objects are never duplicated; a variant holding a `Gd<T>` refers to the same object
+    /// after the copy. All other types are values anyway, so the result is the same as [`clone()`][Clone::clone].
+    ///
+    /// See also [`duplicate_shallow()`][Self::duplicate_shallow].
+    ///
+    /// _Godot equivalent: `variant.duplicate(true)`_
+    pub fn duplicate_deep(&self) -> Self {
+        self.duplicate(true)
+    }
+
+    /// Returns a shallow copy of the value held by this variant.
+    ///
+    /// Arrays, dictionaries and packed arrays are copied, but arrays and dictionaries nested in them are shared with the original.
+    /// For all other types, this behaves like [`duplicate_deep()`][Self::duplicate_deep].
+    ///
+    /// _Godot equivalent: `variant.duplicate(false)`_
+    pub fn duplicate_shallow(&self) -> Self {
+        self.duplicate(false)
+    }
+
+    fn duplicate(&self, deep: bool) -> Self {
+        unsafe {
+            Self::new_with_var_uninit(|variant_ptr| {
+                interface_fn!(variant_duplicate)(
+                    self.var_sys(),
+                    variant_ptr,
+                    sys::conv::bool_to_sys(deep),
+                )
+            })
+        }
+    }
+
     /// Interpret the `Variant` as `bool`.
     ///
     /// Returns `false` only if the variant's current value is the default value for its type. For example:
@@ -227,6 +227,65 @@ mod test {
         assert_eq_approx!(a.coord_max(b), Vector2::new(1.2, 5.6));
     }
 
+    #[test]
+    fn collision_response() {
+        let velocity = Vector2::new(3.0, 2.0);
+        let floor = Vector2::UP;
+
+        assert_eq_approx!(velocity.slide(floor), Vector2::new(3.0, 0.0));
+        assert_eq_approx!(velocity.bounce(floor), Vector2::new(3.0, -2.0));
+
+        let velocity = Vector2::new(1.0, 0.0);
+        let wall = Vector2::new(-1.0, 1.0).normalized();
+
+        assert_eq_approx!(velocity.bounce(wall), Vector2::new(0.0, 1.0));
+        assert_eq_approx!(velocity.reflect(wall), Vector2::new(0.0, -1.0));
+        assert_eq_approx!(velocity.slide(wall), Vector2::new(0.5, 0.5));
+    }
+
+    #[test]
+    fn int_conversions() {
+        let a = Vector2::new(2.5, -2.5);
+        assert_eq!(a.cast_int(), Vector2i::new(2, -2));
+        assert_eq!(a.try_cast_int(), Some(Vector2i::new(2, -2)));
+        assert_eq!(a.floor_to_int(), Vector2i::new(2, -3));
+        assert_eq!(a.round_to_int(), Vector2i::new(3, -3));
+
+        let b = Vector2::new(7.4, -0.4);
+        assert_eq!(b.try_cast_int(), Some(Vector2i::new(7, 0)));
+        assert_eq!(b.floor_to_int(), Vector2i::new(7, -1));
+        assert_eq!(b.round_to_int(), Vector2i::new(7, 0));
+
+        assert_eq!(Vector2::new(real::NAN, 0.0).try_cast_int(), None);
+        assert_eq!(Vector2::new(0.0, real::INFINITY).try_cast_int(), None);
+        assert_eq!(Vector2::new(0.0, 3.0e9).try_cast_int(), None);
+        assert_eq!(Vector2::new(-3.0e9, 0.0).try_cast_int(), None);
+    }
+
+    #[test]
+    fn iter_sum_product() {
+        let vecs = [Vector2::new(1.0, 2.0), Vector2::new(3.0, -4.0)];
+
+        assert_eq_approx!(vecs.iter().sum::<Vector2>(), Vector2::new(4.0, -2.0));
+
+        // Component-wise, not a dot or cross product.
+        assert_eq_approx!(vecs.iter().product::<Vector2>(), Vector2::new(3.0, -8.0));
+        assert_eq_approx!(
+            vecs.into_iter().product::<Vector2>(),
+            Vector2::new(3.0, -8.0)
+        );
+
+        // Empty iterators yield the identity elements.
+        assert_eq!(
+            std::iter::empty::<Vector2>().sum::<Vector2>(),
+            Vector2::ZERO
+        );
+        assert_eq!(
+            std::iter::empty::<Vector2>().product::<Vector2>(),
+            Vector2::ONE
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -334,6 +334,16 @@ mod test {
         assert_eq_approx!(a.coord_max(b), Vector3::new(1.2, 5.6, 5.6));
     }
 
+    #[test]
+    fn int_conversions() {
+        let a = Vector3::new(1.5, -1.5, -0.2);
+        assert_eq!(a.try_cast_int(), Some(Vector3i::new(1, -1, 0)));
+        assert_eq!(a.floor_to_int(), Vector3i::new(1, -2, -1));
+        assert_eq!(a.round_to_int(), Vector3i::new(2, -2, 0));
+
+        assert_eq!(Vector3::new(0.0, 0.0, real::NAN).try_cast_int(), None);
+    }
+
     #[test]
     fn test_slerp() {
         // The halfway point of a slerp operation on two vectors on a circle is the halfway point of
@@ -406,6 +416,24 @@ mod test {
         assert_eq_approx!(sum_refs, Vector3::new(12.0, 15.0, 18.0));
     }
 
+    #[test]
+    fn collision_response() {
+        let velocity = Vector3::new(3.0, -2.0, 1.0);
+        let floor = Vector3::UP;
+
+        assert_eq_approx!(velocity.slide(floor), Vector3::new(3.0, 0.0, 1.0));
+        assert_eq_approx!(velocity.bounce(floor), Vector3::new(3.0, 2.0, 1.0));
+        assert_eq_approx!(velocity.reflect(floor), Vector3::new(-3.0, -2.0, -1.0));
+
+        // Moving along +X into a wall rotated by 45 degrees, whose normal points back towards -X and up.
+        let velocity = Vector3::new(1.0, 0.0, 0.0);
+        let wall = Vector3::new(-1.0, 1.0, 0.0).normalized();
+
+        assert_eq_approx!(velocity.bounce(wall), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq_approx!(velocity.reflect(wall), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq_approx!(velocity.slide(wall), Vector3::new(0.5, 0.5, 0.0));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
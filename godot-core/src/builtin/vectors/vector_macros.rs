@@ -169,6 +169,9 @@ macro_rules! impl_iter_vector_reduction {
     ) => {
         impl std::iter::$Operator<Self> for $Vector {
             #[doc = concat!("Element-wise ", stringify!($func), " of all vectors in the iterator.")]
+            ///
+            /// Each component of the result is computed from the same component of all vectors; an empty iterator yields the
+            /// identity vector for the operation (`ZERO` or `ONE`).
             fn $func<I>(iter: I) -> Self
             where
                 I: Iterator<Item = Self>,
@@ -179,6 +182,9 @@ macro_rules! impl_iter_vector_reduction {
 
         impl<'a> std::iter::$Operator<&'a Self> for $Vector {
             #[doc = concat!("Element-wise ", stringify!($func), " of all vectors in the iterator.")]
+            ///
+            /// Each component of the result is computed from the same component of all vectors; an empty iterator yields the
+            /// identity vector for the operation (`ZERO` or `ONE`).
             fn $func<I>(iter: I) -> Self
             where
                 I: Iterator<Item = &'a Self>,
@@ -500,6 +506,16 @@ macro_rules! impl_vector_fns {
     }
 }
 
+pub(super) fn checked_truncate_i32(value: crate::builtin::real) -> Option<i32> {
+    // Compare in f64, where all i32 values are exact. NaN fails both comparisons.
+    let truncated = (value as f64).trunc();
+    if truncated >= i32::MIN as f64 && truncated <= i32::MAX as f64 {
+        Some(truncated as i32)
+    } else {
+        None
+    }
+}
+
 pub(super) fn snap_one(mut value: i32, step: i32) -> i32 {
     assert!(
         value != i32::MIN || step != -1,
@@ -581,6 +597,47 @@ macro_rules! impl_float_vector_fns {
                 <$VectorInt>::new( $(self.$comp as i32),* )
             }
 
+            /// Converts to a vector with integer components, truncating towards zero, or returns `None` if any component does not fit.
+            ///
+            /// Unlike [`cast_int()`][Self::cast_int], which saturates, this fails if a component is NaN, infinite or outside the
+            /// range of [`i32`] after truncation.
+            ///
+            #[doc = concat!("The reverse conversion is [`", stringify!($VectorInt), "::cast_float()`][", stringify!($VectorInt), "::cast_float].")]
+            /// It is deliberately not offered as a `From` impl, since it is not lossless: with single-precision [`real`](type.real.html),
+            /// integers beyond ±2<sup>24</sup> cannot be represented exactly.
+            #[doc(alias = "to_i32")]
+            #[inline]
+            pub fn try_cast_int(self) -> Option<$VectorInt> {
+                use crate::builtin::vectors::vector_macros::checked_truncate_i32;
+
+                Some(<$VectorInt>::new(
+                    $(
+                        checked_truncate_i32(self.$comp)?
+                    ),*
+                ))
+            }
+
+            /// Rounds all components down (towards negative infinity) and converts to a vector with integer components.
+            ///
+            /// Equivalent to `self.floor().cast_int()`. Use this to map world positions to grid cells, where `-0.5` belongs to cell `-1`.
+            ///
+            #[doc = concat!("To convert back, use [`", stringify!($VectorInt), "::cast_float()`][", stringify!($VectorInt), "::cast_float]; ")]
+            /// see [`try_cast_int()`][Self::try_cast_int] for why there is no `From` impl.
+            #[doc(alias = "floor_to_i")]
+            #[inline]
+            pub fn floor_to_int(self) -> $VectorInt {
+                self.floor().cast_int()
+            }
+
+            /// Rounds all components to the nearest integer (halfway cases away from zero) and converts to a vector with integer components.
+            ///
+            /// Equivalent to `self.round().cast_int()`.
+            #[doc(alias = "round_to_i")]
+            #[inline]
+            pub fn round_to_int(self) -> $VectorInt {
+                self.round().cast_int()
+            }
+
             /// Returns a new vector with all components rounded down (towards negative infinity).
             #[inline]
             pub fn floor(self) -> Self {
@@ -1033,6 +1090,9 @@ macro_rules! impl_vector2_vector3_fns {
 
             /// Returns a new vector "bounced off" from a plane defined by the given normal.
             ///
+            /// The component along `n` is inverted, while the component parallel to the plane is kept. This is the usual collision
+            /// response, e.g. a velocity pointing into a floor with normal `UP` is turned upwards. Equivalent to `-self.reflect(n)`.
+            ///
             /// # Panics
             /// If `n` is not normalized.
             #[inline]
@@ -1064,6 +1124,9 @@ macro_rules! impl_vector2_vector3_fns {
 
             /// Returns the result of reflecting the vector defined by the given direction vector `n`.
             ///
+            /// Following Godot, this mirrors the vector across the line spanned by `n`: the component along `n` is kept, while the
+            /// component parallel to the plane is inverted. To bounce a velocity off a surface with normal `n`, use [`bounce()`][Self::bounce].
+            ///
             /// # Panics
             /// If `n` is not normalized.
             #[inline]
@@ -1074,6 +1137,8 @@ macro_rules! impl_vector2_vector3_fns {
 
             /// Returns a new vector slid along a plane defined by the given normal.
             ///
+            /// The component along `n` is removed, so the result is parallel to the plane, regardless of which side of it `n` points to.
+            ///
             /// # Panics
             /// If `n` is not normalized.
             #[inline]
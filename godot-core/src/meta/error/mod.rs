@@ -10,7 +10,11 @@
 mod call_error;
 mod convert_error;
 mod io_error;
+mod node_path_error;
+mod regex_error;
 
 pub use call_error::*;
 pub use convert_error::*;
 pub use io_error::*;
+pub use node_path_error::*;
+pub use regex_error::*;
@@ -0,0 +1,225 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when validating a [`NodePath`][crate::builtin::NodePath].
+///
+/// Returned by [`NodePath::try_new()`][crate::builtin::NodePath::try_new], for which Godot itself accepts most of these inputs silently,
+/// e.g. by collapsing `//` or ignoring a trailing `:`. Also returned when converting to a
+/// [`PropertyPath`][crate::builtin::strings::PropertyPath].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum NodePathError {
+    /// A name between `/` separators is empty, e.g. in `"A//B"` or `"A/"`.
+    EmptyName {
+        /// Index of the empty name among all names.
+        index: usize,
+    },
+
+    /// A subname between `:` separators is empty, e.g. in `"Node:"` or `"Node::prop"`.
+    EmptySubname {
+        /// Index of the empty subname among all subnames.
+        index: usize,
+    },
+
+    /// A name or subname contains a character that is not allowed in it.
+    IllegalCharacter {
+        /// The offending name or subname.
+        component: String,
+
+        /// The character that is not allowed.
+        character: char,
+    },
+
+    /// The path has no subnames, so it does not address a property.
+    NoSubnames,
+}
+
+impl fmt::Display for NodePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName { index } => write!(f, "node path has empty name at index {index}"),
+            Self::EmptySubname { index } => {
+                write!(f, "node path has empty subname at index {index}")
+            }
+            Self::IllegalCharacter {
+                component,
+                character,
+            } => write!(
+                f,
+                "node path component '{component}' contains illegal character {character:?}"
+            ),
+            Self::NoSubnames => {
+                write!(f, "node path has no subnames, so it is not a property path")
+            }
+        }
+    }
+}
+
+impl Error for NodePathError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when a [`NodePath`][crate::builtin::NodePath] cannot be converted to a Rust `String` without loss.
+///
+/// Returned by [`NodePath::try_to_string()`][crate::builtin::NodePath::try_to_string].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct NodePathEncodingError {
+    position: usize,
+}
+
+impl NodePathEncodingError {
+    pub(crate) fn new(position: usize) -> Self {
+        Self { position }
+    }
+
+    /// Index (in characters) of the first replacement character U+FFFD in the path.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for NodePathEncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "node path contains replacement character U+FFFD at position {}",
+            self.position
+        )
+    }
+}
+
+impl Error for NodePathEncodingError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when decoding a [`NodePath`][crate::builtin::NodePath] from its binary wire format.
+///
+/// Returned by [`NodePath::from_bytes()`][crate::builtin::NodePath::from_bytes].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum NodePathDecodeError {
+    /// The version tag in the first byte is not supported by this version of the library.
+    UnsupportedVersion(u8),
+
+    /// The input ends before the version tag or within the length prefix.
+    TruncatedLength,
+
+    /// The input ends before the announced number of text bytes; holds the number of missing bytes.
+    TruncatedData(usize),
+
+    /// The text bytes are not valid UTF-8.
+    InvalidUtf8,
+
+    /// The input continues after the announced number of text bytes; holds the number of extra bytes.
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for NodePathDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported node path encoding version {version}")
+            }
+            Self::TruncatedLength => write!(f, "encoded node path is truncated"),
+            Self::TruncatedData(count) => {
+                write!(f, "encoded node path is missing {count} bytes of text")
+            }
+            Self::InvalidUtf8 => write!(f, "encoded node path is not valid UTF-8"),
+            Self::TrailingBytes(count) => {
+                write!(f, "encoded node path is followed by {count} extra bytes")
+            }
+        }
+    }
+}
+
+impl Error for NodePathDecodeError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when parsing a GDScript node path literal such as `^"Parent/Child"`.
+///
+/// Returned by [`NodePath::from_gdscript_literal()`][crate::builtin::NodePath::from_gdscript_literal]. Positions are byte offsets into
+/// the whole literal, including the leading `^"`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum LiteralParseError {
+    /// The input does not start with `^"` or does not end with `"`.
+    MissingDelimiters,
+
+    /// A `"` inside the literal is not escaped.
+    UnescapedQuote {
+        /// Byte offset of the quote.
+        position: usize,
+    },
+
+    /// A backslash is followed by an unknown or incomplete escape sequence.
+    InvalidEscape {
+        /// Byte offset of the backslash.
+        position: usize,
+    },
+}
+
+impl fmt::Display for LiteralParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDelimiters => {
+                write!(f, "node path literal must be enclosed in ^\" and \"")
+            }
+            Self::UnescapedQuote { position } => {
+                write!(
+                    f,
+                    "node path literal has unescaped quote at byte {position}"
+                )
+            }
+            Self::InvalidEscape { position } => {
+                write!(
+                    f,
+                    "node path literal has invalid escape sequence at byte {position}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for LiteralParseError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error when a single component of a [`NodePath`][crate::builtin::NodePath] would not be parsed as one component.
+///
+/// Returned by [`StrictNodePathBuilder`][crate::builtin::strings::StrictNodePathBuilder] when pushing names and subnames.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ComponentError {
+    /// The component is empty.
+    Empty,
+
+    /// The component contains a separator (`/` or `:`) or a null byte.
+    IllegalCharacter {
+        /// The rejected component.
+        component: String,
+
+        /// The first character that is not allowed.
+        character: char,
+    },
+}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "node path component is empty"),
+            Self::IllegalCharacter {
+                component,
+                character,
+            } => write!(
+                f,
+                "node path component '{component}' contains illegal character {character:?}"
+            ),
+        }
+    }
+}
+
+impl Error for ComponentError {}
@@ -0,0 +1,51 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::global::Error as GodotError;
+
+/// Error when a regular expression pattern cannot be compiled by Godot's `RegEx`.
+///
+/// Returned by [`GString::regex_match()`][crate::builtin::GString::regex_match].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RegexError {
+    pattern: String,
+    godot_error: GodotError,
+}
+
+impl RegexError {
+    pub(crate) fn new(pattern: String, godot_error: GodotError) -> Self {
+        Self {
+            pattern,
+            godot_error,
+        }
+    }
+
+    /// The pattern that failed to compile.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// The error code reported by Godot.
+    pub fn godot_error(&self) -> GodotError {
+        self.godot_error
+    }
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to compile regex pattern '{}'; Godot error: {:?}",
+            self.pattern, self.godot_error
+        )
+    }
+}
+
+impl Error for RegexError {}
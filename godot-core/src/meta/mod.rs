@@ -40,12 +40,14 @@ mod godot_convert;
 mod method_info;
 mod property_info;
 mod sealed;
+mod signal_args;
 mod signature;
 mod traits;
 
 pub mod error;
 pub use class_name::ClassName;
 pub use godot_convert::{FromGodot, GodotConvert, ToGodot};
+pub use signal_args::SignalArgs;
 pub use traits::{ArrayElement, GodotType, PackedArrayElement};
 
 pub(crate) use crate::impl_godot_as_self;
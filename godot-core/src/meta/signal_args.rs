@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Variant;
+use crate::meta::ToGodot;
+
+/// Tuple of arguments that can be passed to [`Signal::emit_typed()`][crate::builtin::Signal::emit_typed].
+///
+/// Implemented for tuples of up to 10 elements, each of which implements [`ToGodot`].
+pub trait SignalArgs {
+    /// Converts all arguments to variants, in order.
+    fn to_variants(&self) -> Vec<Variant>;
+}
+
+macro_rules! impl_signal_args_for_tuple {
+    ($($Pn:ident $n:tt),*) => {
+        impl<$($Pn: ToGodot,)*> SignalArgs for ($($Pn,)*) {
+            fn to_variants(&self) -> Vec<Variant> {
+                vec![$(self.$n.to_variant(),)*]
+            }
+        }
+    };
+}
+
+impl_signal_args_for_tuple!();
+impl_signal_args_for_tuple!(P0 0);
+impl_signal_args_for_tuple!(P0 0, P1 1);
+impl_signal_args_for_tuple!(P0 0, P1 1, P2 2);
+impl_signal_args_for_tuple!(P0 0, P1 1, P2 2, P3 3);
+impl_signal_args_for_tuple!(P0 0, P1 1, P2 2, P3 3, P4 4);
+impl_signal_args_for_tuple!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5);
+impl_signal_args_for_tuple!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6);
+impl_signal_args_for_tuple!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7);
+impl_signal_args_for_tuple!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8);
+impl_signal_args_for_tuple!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5, P6 6, P7 7, P8 8, P9 9);
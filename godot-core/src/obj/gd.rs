@@ -21,7 +21,7 @@ use crate::meta::{
 };
 use crate::obj::{
     bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    RawGd,
+    RawGd, WeakGd,
 };
 use crate::private::callbacks;
 use crate::registry::property::{Export, Var};
@@ -195,8 +195,8 @@ where
 impl<T: GodotClass> Gd<T> {
     /// Looks up the given instance ID and returns the associated object, if possible.
     ///
-    /// If no such instance ID is registered, or if the dynamic type of the object behind that instance ID
-    /// is not compatible with `T`, then `None` is returned.
+    /// If no such instance ID is registered (e.g. because the object has been freed in the meantime), or if the dynamic type of the
+    /// object behind that instance ID is not compatible with `T`, then an error is returned. A dangling `Gd` is never produced.
     pub fn try_from_instance_id(instance_id: InstanceId) -> Result<Self, ConvertError> {
         let ptr = classes::object_ptr_from_id(instance_id);
 
@@ -268,6 +268,14 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Creates a [`WeakGd`] pointing to the same object, which does not keep it alive.
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::from_instance_id(self.instance_id())
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -371,17 +379,17 @@ impl<T: GodotClass> Gd<T> {
     /// **Downcast:** try to convert into a smart pointer to a derived class.
     ///
     /// If `T`'s dynamic type is not `Derived` or one of its subclasses, `Err(self)` is returned, meaning you can reuse the original
-    /// object for further casts.
+    /// object for further casts. This never panics; in particular, a dead instance is also returned as `Err(self)`.
     pub fn try_cast<Derived>(self) -> Result<Gd<Derived>, Self>
     where
         Derived: GodotClass + Inherits<T>,
     {
-	    if self.is_instance_valid() {
-		    // Separate method due to more restrictive bounds.
-		    self.owned_cast()
-	    } else {
-		    Err(self)
-	    }
+        if self.is_instance_valid() {
+            // Separate method due to more restrictive bounds.
+            self.owned_cast()
+        } else {
+            Err(self)
+        }
     }
 
     /// ⚠️ **Downcast:** convert into a smart pointer to a derived class. Panics on error.
@@ -18,6 +18,7 @@ mod instance_id;
 mod onready;
 mod raw_gd;
 mod traits;
+mod weak_gd;
 
 pub(crate) mod object_arg;
 pub(crate) mod rtti;
@@ -30,6 +31,7 @@ pub use object_arg::AsObjectArg;
 pub use onready::*;
 pub use raw_gd::*;
 pub use traits::*;
+pub use weak_gd::WeakGd;
 
 pub mod bounds;
 pub mod script;
@@ -0,0 +1,85 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Weak pointer to objects owned by the Godot engine.
+///
+/// A `WeakGd<T>` only stores the [`InstanceId`] of an object. It does not keep the object alive, neither for reference-counted nor
+/// for manually-managed types. This makes it suitable for caching references to nodes that may be freed by the scene, or for breaking
+/// reference cycles between `RefCounted` objects.
+///
+/// To access the object, call [`upgrade()`][Self::upgrade], which returns a strong [`Gd<T>`] if the object is still alive.
+///
+/// Created by [`Gd::downgrade()`].
+pub struct WeakGd<T: GodotClass> {
+    instance_id: InstanceId,
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    pub(crate) fn from_instance_id(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong pointer to the object, or `None` if it has been destroyed in the meantime.
+    ///
+    /// For reference-counted types, the returned `Gd<T>` keeps the object alive for as long as it exists.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+
+    /// Returns `true` if the object is still alive.
+    ///
+    /// Note that an object may be destroyed right after this check. Prefer [`upgrade()`][Self::upgrade] if you need to access it.
+    pub fn is_alive(&self) -> bool {
+        self.upgrade().is_some()
+    }
+
+    /// Returns the instance ID of the referenced object, regardless of whether it is still alive.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+// Manual impls, to avoid `T: Clone` etc. bounds from derives.
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> std::hash::Hash for WeakGd<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("WeakGd")
+            .field("class", &T::class_name())
+            .field("id", &self.instance_id)
+            .finish()
+    }
+}
@@ -121,6 +121,10 @@
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!
+//! * **`camino`**
+//!
+//!   Conversions between [`NodePath`](builtin::NodePath) and the UTF-8 path types of [camino](https://docs.rs/camino).
+//!
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/godot-rust/assets/master/gdext/ferris.svg"
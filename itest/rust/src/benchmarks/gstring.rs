@@ -0,0 +1,42 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::hint::black_box;
+
+use crate::framework::bench;
+
+use godot::builtin::strings::GStringBuilder;
+use godot::builtin::{GString, VariantOperator};
+use godot::meta::ToGodot;
+
+const PUSHES: usize = 10_000;
+const PART: &str = "part;";
+
+#[bench(repeat = 1)]
+fn gstring_concat_builder() -> GString {
+    let mut builder = GStringBuilder::new();
+    for _ in 0..PUSHES {
+        builder.push_str(black_box(PART));
+    }
+
+    builder.build()
+}
+
+#[bench(repeat = 1)]
+fn gstring_concat_naive() -> GString {
+    let part = GString::from(PART).to_variant();
+
+    // Same as `string += part` in GDScript: every step allocates a new Godot string.
+    let mut string = GString::new().to_variant();
+    for _ in 0..PUSHES {
+        string = string
+            .evaluate(black_box(&part), VariantOperator::ADD)
+            .unwrap();
+    }
+
+    string.to()
+}
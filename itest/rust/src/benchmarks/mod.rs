@@ -18,6 +18,8 @@ use godot::register::GodotClass;
 use crate::framework::bench;
 
 mod color;
+mod gstring;
+mod node_path;
 
 #[bench]
 fn builtin_string_ctor() -> GString {
@@ -29,6 +31,11 @@ fn builtin_stringname_ctor() -> StringName {
     StringName::from("some test string")
 }
 
+#[bench]
+fn builtin_stringname_ctor_ascii() -> StringName {
+    StringName::from_ascii("some test string")
+}
+
 #[bench]
 fn builtin_rust_call() -> bool {
     let point = black_box(Vector2i::new(50, 60));
@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// NodePath needs a running engine, so it cannot be benchmarked with criterion; the itest harness is used instead.
+
+use std::hint::black_box;
+use std::mem::ManuallyDrop;
+
+use crate::framework::bench;
+
+use godot::builtin::{GString, NodePath};
+
+const SHORT: &str = "Player";
+const DEEP: &str = "/root/Main/World/Level/Enemies/Goblin/Body/Sprite";
+const SUBNAMES: &str = "World/Goblin/Sprite:material:shader_parameter:albedo:r";
+
+thread_local! {
+    // ManuallyDrop: thread-locals may be destroyed after the engine has shut down.
+    static SHORT_PATH: ManuallyDrop<NodePath> = ManuallyDrop::new(NodePath::from(SHORT));
+    static DEEP_PATH: ManuallyDrop<NodePath> = ManuallyDrop::new(NodePath::from(DEEP));
+    static SUBNAMES_PATH: ManuallyDrop<NodePath> = ManuallyDrop::new(NodePath::from(SUBNAMES));
+}
+
+#[bench]
+fn node_path_parse_short() -> NodePath {
+    NodePath::from(black_box(SHORT))
+}
+
+#[bench]
+fn node_path_parse_deep() -> NodePath {
+    NodePath::from(black_box(DEEP))
+}
+
+#[bench]
+fn node_path_parse_subnames() -> NodePath {
+    NodePath::from(black_box(SUBNAMES))
+}
+
+#[bench]
+fn node_path_clone_short() -> NodePath {
+    SHORT_PATH.with(|path| NodePath::clone(black_box(path)))
+}
+
+#[bench]
+fn node_path_clone_deep() -> NodePath {
+    DEEP_PATH.with(|path| NodePath::clone(black_box(path)))
+}
+
+#[bench]
+fn node_path_clone_subnames() -> NodePath {
+    SUBNAMES_PATH.with(|path| NodePath::clone(black_box(path)))
+}
+
+#[bench]
+fn node_path_to_gstring_short() -> GString {
+    SHORT_PATH.with(|path| GString::from(black_box(&**path)))
+}
+
+#[bench]
+fn node_path_to_gstring_deep() -> GString {
+    DEEP_PATH.with(|path| GString::from(black_box(&**path)))
+}
+
+#[bench]
+fn node_path_to_gstring_subnames() -> GString {
+    SUBNAMES_PATH.with(|path| GString::from(black_box(&**path)))
+}
+
+#[bench]
+fn node_path_hash_short() -> u32 {
+    SHORT_PATH.with(|path| black_box(path).hash())
+}
+
+#[bench]
+fn node_path_hash_deep() -> u32 {
+    DEEP_PATH.with(|path| black_box(path).hash())
+}
+
+#[bench]
+fn node_path_hash_subnames() -> u32 {
+    SUBNAMES_PATH.with(|path| black_box(path).hash())
+}
+
+#[bench]
+fn node_path_iter_components_live_x10() -> usize {
+    DEEP_PATH.with(|path| {
+        let path = black_box(&**path);
+        (0..10).map(|_| path.iter_components().count()).sum()
+    })
+}
+
+#[bench]
+fn node_path_iter_components_snapshot_x10() -> usize {
+    DEEP_PATH.with(|path| {
+        let components = black_box(&**path).components();
+        (0..10).map(|_| components.iter().count()).sum()
+    })
+}
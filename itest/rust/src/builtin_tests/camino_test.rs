@@ -0,0 +1,42 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use camino::{Utf8Path, Utf8PathBuf};
+use godot::builtin::NodePath;
+
+use crate::framework::itest;
+
+#[itest]
+fn camino_node_path_roundtrip() {
+    let path = NodePath::from("/root/Level/Player");
+
+    let path_buf = path.to_utf8_path_buf();
+    assert_eq!(path_buf, Utf8PathBuf::from("/root/Level/Player"));
+    assert!(path_buf.has_root());
+
+    assert_eq!(NodePath::from(path_buf.as_path()), path);
+}
+
+#[itest]
+fn camino_node_path_relative() {
+    let utf8_path = Utf8Path::new("Level/Enemies/Gøblin");
+    let path = NodePath::from(utf8_path);
+
+    assert_eq!(path, NodePath::from("Level/Enemies/Gøblin"));
+    assert_eq!(path.to_utf8_path_buf(), utf8_path);
+
+    let path_buf = path.to_utf8_path_buf();
+    let components: Vec<&str> = path_buf.iter().collect();
+    assert_eq!(components, ["Level", "Enemies", "Gøblin"]);
+}
+
+#[itest]
+#[cfg(windows)]
+fn camino_node_path_windows_separators() {
+    let path = NodePath::from(Utf8Path::new(r"Level\Enemies\Goblin"));
+    assert_eq!(path, NodePath::from("Level/Enemies/Goblin"));
+}
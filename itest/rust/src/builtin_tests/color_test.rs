@@ -6,7 +6,7 @@
  */
 
 use crate::framework::itest;
-use godot::builtin::math::assert_eq_approx;
+use godot::builtin::math::{assert_eq_approx, ApproxEq};
 use godot::builtin::{Color, ColorChannelOrder, ColorHsv};
 
 #[itest]
@@ -97,6 +97,33 @@ fn color_blend() {
     );
 }
 
+#[itest]
+fn color_blend_half_transparent() {
+    let base = Color::from_rgb(1.0, 0.0, 0.0);
+    let over = Color::from_rgba(0.0, 0.0, 1.0, 0.5);
+    assert_eq_approx!(base.blend(over), Color::from_rgba(0.5, 0.0, 0.5, 1.0));
+
+    // Blending over a fully transparent base yields the overlay.
+    let transparent = Color::from_rgba(0.3, 0.3, 0.3, 0.0);
+    assert_eq_approx!(transparent.blend(over), over);
+
+    // Fully transparent on fully transparent: transparent black.
+    assert_eq_approx!(
+        transparent.blend(transparent),
+        Color::from_rgba(0.0, 0.0, 0.0, 0.0)
+    );
+}
+
+#[itest]
+fn color_lerp() {
+    let from = Color::from_rgba(0.0, 0.2, 1.0, 1.0);
+    let to = Color::from_rgba(1.0, 0.6, 0.0, 0.0);
+
+    assert_eq_approx!(from.lerp(to, 0.0), from);
+    assert_eq_approx!(from.lerp(to, 0.5), Color::from_rgba(0.5, 0.4, 0.5, 0.5));
+    assert_eq_approx!(from.lerp(to, 1.0), to);
+}
+
 #[itest]
 fn color_to_u32() {
     let c = Color::from_html("#01020304").unwrap();
@@ -198,3 +225,61 @@ fn color_hsv_multi_roundtrip() {
         assert_eq_approx!(original, c_back);
     }
 }
+
+#[itest]
+fn color_from_hsva() {
+    assert_eq_approx!(Color::from_hsva(0.0, 1.0, 1.0, 1.0), Color::RED);
+    assert_eq_approx!(
+        Color::from_hsva(1.0 / 3.0, 1.0, 1.0, 0.5),
+        Color::from_rgba(0.0, 1.0, 0.0, 0.5)
+    );
+    assert_eq_approx!(
+        Color::from_hsva(0.0, 0.0, 0.25, 1.0),
+        Color::from_rgb(0.25, 0.25, 0.25)
+    );
+}
+
+#[itest]
+fn color_hsv_accessors() {
+    let red = Color::RED;
+    assert_eq_approx!(red.h(), 0.0);
+    assert_eq_approx!(red.s(), 1.0);
+    assert_eq_approx!(red.v(), 1.0);
+
+    let gray = Color::from_rgb(0.5, 0.5, 0.5);
+    assert_eq_approx!(gray.h(), 0.0);
+    assert_eq_approx!(gray.s(), 0.0);
+    assert_eq_approx!(gray.v(), 0.5);
+
+    // HDR colors are allowed.
+    let bright_blue = Color::from_rgb(0.0, 0.0, 2.0);
+    assert_eq_approx!(bright_blue.h(), 2.0 / 3.0);
+    assert_eq_approx!(bright_blue.v(), 2.0);
+
+    for (r, g, b) in COLOR_HSV_CASES_RGB {
+        let original = Color::from_rgba(r, g, b, 0.75);
+        let hsv = original.to_hsv();
+        assert_eq_approx!(original.h(), hsv.h);
+        assert_eq_approx!(original.s(), hsv.s);
+        assert_eq_approx!(original.v(), hsv.v);
+
+        let back = Color::from_hsva(
+            original.h() as f64,
+            original.s() as f64,
+            original.v() as f64,
+            original.a as f64,
+        );
+        assert_eq_approx!(back, original, "r: {r}, g: {g}, b: {b}");
+    }
+}
+
+#[itest]
+fn color_is_equal_approx() {
+    let color = Color::from_rgba(0.2, 0.4, 0.6, 1.0);
+    let within = Color::from_rgba(0.2 + 0.000_001, 0.4, 0.6, 1.0);
+    let outside = Color::from_rgba(0.2 + 0.001, 0.4, 0.6, 1.0);
+
+    assert!(color.approx_eq(&within));
+    assert!(!color.approx_eq(&outside));
+    assert!(!color.approx_eq(&Color::from_rgba(0.2, 0.4, 0.6, 0.999)));
+}
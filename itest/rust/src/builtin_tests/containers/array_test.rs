@@ -297,6 +297,124 @@ fn array_extend() {
     assert_eq!(array, array![1, 2, 3, 4]);
 }
 
+#[itest]
+fn array_extend_from_iter() {
+    let mut array: Array<i64> = array![1, 2];
+    array.extend(vec![3, 4, 5]);
+    assert_eq!(array.len(), 5);
+    assert_eq!(array, array![1, 2, 3, 4, 5]);
+
+    // Size hint of 0, elements are pushed one by one.
+    array.extend((6..).take_while(|&i| i <= 7));
+    assert_eq!(array, array![1, 2, 3, 4, 5, 6, 7]);
+
+    let mut empty: Array<i64> = Array::new();
+    empty.extend(vec![10, 20, 30]);
+    assert_eq!(empty.len(), 3);
+    assert_eq!(empty, array![10, 20, 30]);
+
+    empty.extend(Vec::new());
+    assert_eq!(empty, array![10, 20, 30]);
+}
+
+#[itest]
+fn array_extend_wrong_size_hint() {
+    // Iterator that promises more elements than it yields.
+    struct Overpromising(std::ops::Range<i64>);
+
+    impl Iterator for Overpromising {
+        type Item = i64;
+
+        fn next(&mut self) -> Option<i64> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (10, None)
+        }
+    }
+
+    let mut array: Array<i64> = array![1];
+    array.extend(Overpromising(2..4));
+    assert_eq!(array, array![1, 2, 3]);
+}
+
+#[itest]
+fn array_extend_panicking_iter() {
+    let mut array: Array<i64> = array![1];
+
+    // Size hint of 4, but panics after 2 elements.
+    expect_panic("iterator panics during extend", || {
+        array.extend((2..6).map(|i| if i < 4 { i } else { panic!("boom") }));
+    });
+
+    // No placeholder slots remain for the elements that were never yielded.
+    assert_eq!(array.len(), 3);
+    assert_eq!(array, array![1, 2, 3]);
+}
+
+#[itest]
+fn array_retain() {
+    let mut array: Array<i64> = array![1, 2, 3, 4, 5, 6];
+    let shared = array.clone();
+
+    let mut visited = Vec::new();
+    array.retain(|&x| {
+        visited.push(x);
+        x % 2 == 0
+    });
+
+    assert_eq!(array, array![2, 4, 6]);
+    assert_eq!(
+        visited,
+        vec![1, 2, 3, 4, 5, 6],
+        "visits each element in order"
+    );
+    assert_eq!(shared, array![2, 4, 6], "modifies array in place");
+
+    array.retain(|_| false);
+    assert!(array.is_empty());
+}
+
+#[itest]
+fn array_drain() {
+    let mut array: Array<i64> = array![1, 2, 3, 4, 5];
+
+    let drained: Vec<i64> = array.drain(1..3).collect();
+    assert_eq!(drained, vec![2, 3]);
+    assert_eq!(array, array![1, 4, 5]);
+
+    let drained: Vec<i64> = array.drain(2..).collect();
+    assert_eq!(drained, vec![5]);
+    assert_eq!(array, array![1, 4]);
+
+    let drained: Vec<i64> = array.drain(..).collect();
+    assert_eq!(drained, vec![1, 4]);
+    assert!(array.is_empty());
+
+    expect_panic("drain range out of bounds", || {
+        let mut array: Array<i64> = array![1, 2];
+        let _ = array.drain(1..3);
+    });
+}
+
+#[itest]
+fn array_binary_search_by() {
+    let array: Array<i64> = array![1, 3, 5, 7, 9];
+
+    assert_eq!(array.binary_search_by(|x| x.cmp(&1)), Ok(0));
+    assert_eq!(array.binary_search_by(|x| x.cmp(&7)), Ok(3));
+    assert_eq!(array.binary_search_by(|x| x.cmp(&9)), Ok(4));
+
+    // Missing elements yield the insertion point.
+    assert_eq!(array.binary_search_by(|x| x.cmp(&0)), Err(0));
+    assert_eq!(array.binary_search_by(|x| x.cmp(&4)), Err(2));
+    assert_eq!(array.binary_search_by(|x| x.cmp(&10)), Err(5));
+
+    let empty: Array<i64> = Array::new();
+    assert_eq!(empty.binary_search_by(|x| x.cmp(&1)), Err(0));
+}
+
 #[itest]
 fn array_sort() {
     let mut array = array![2, 1];
@@ -77,6 +77,24 @@ fn callable_object_method() {
     assert_eq!(Callable::invalid().method_name(), None);
 }
 
+#[itest]
+fn callable_freed_object() {
+    let node = Node2D::new_alloc();
+    let id = node.instance_id();
+    let callable = node.callable("set_position");
+
+    assert!(callable.is_valid());
+    assert_eq!(callable.object(), Some(node.clone().upcast::<Object>()));
+
+    node.free();
+
+    assert!(!callable.is_valid());
+    assert!(!callable.is_null());
+    assert_eq!(callable.object(), None);
+    assert_eq!(callable.object_id(), Some(id));
+    assert_eq!(callable.method_name(), Some("set_position".into()));
+}
+
 #[itest]
 fn callable_call() {
     let obj = CallableTestObj::new_gd();
@@ -111,6 +129,17 @@ fn callable_call_return() {
     assert_eq!(callable.callv(varray!["string"]), Variant::nil());
 }
 
+#[itest]
+fn callable_call_deferred() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("foo");
+
+    callable.call_deferred(&[10.to_variant()]);
+
+    // Deferred calls are executed at idle time, i.e. after the current test has finished.
+    assert_eq!(obj.bind().value, 0);
+}
+
 #[itest]
 fn callable_call_engine() {
     let obj = Node2D::new_alloc();
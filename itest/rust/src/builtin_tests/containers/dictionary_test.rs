@@ -210,6 +210,32 @@ fn dictionary_get() {
     assert_eq!(dictionary.get("foobar"), None, "key = \"foobar\"");
 }
 
+#[itest]
+fn dictionary_get_as() {
+    let dictionary = dict! {
+        "foo": 0,
+        "baz": "foobar",
+        "nil": Variant::nil(),
+    };
+
+    // Present, correct type.
+    assert_eq!(
+        dictionary.get_as::<_, i64>("foo").map(Result::ok),
+        Some(Some(0))
+    );
+    assert_eq!(
+        dictionary.get_as::<_, String>("baz").map(Result::ok),
+        Some(Some(String::from("foobar")))
+    );
+
+    // Present, wrong type.
+    assert!(matches!(dictionary.get_as::<_, i64>("baz"), Some(Err(_))));
+    assert!(matches!(dictionary.get_as::<_, i64>("nil"), Some(Err(_))));
+
+    // Absent.
+    assert!(dictionary.get_as::<_, i64>("missing").is_none());
+}
+
 #[itest]
 fn dictionary_at() {
     let dictionary = dict! {
@@ -301,6 +327,55 @@ fn dictionary_extend() {
     assert_eq!(dictionary.get("bar"), Some("new".to_variant()));
 }
 
+#[itest]
+fn dictionary_merged() {
+    let defaults = dict! {
+        "volume": 50,
+        "fullscreen": false,
+    };
+    let user = dict! {
+        "volume": 80,
+        "language": "de",
+    };
+
+    // Keep existing values.
+    let merged = defaults.merged(&user, false);
+    assert_eq!(
+        merged,
+        dict! {
+            "volume": 50,
+            "fullscreen": false,
+            "language": "de",
+        }
+    );
+
+    // Overwrite existing values.
+    let merged = defaults.merged(&user, true);
+    assert_eq!(
+        merged,
+        dict! {
+            "volume": 80,
+            "fullscreen": false,
+            "language": "de",
+        }
+    );
+
+    // Disjoint dictionaries: union, regardless of overwrite.
+    let other = dict! { "vsync": true };
+    let expected = dict! {
+        "volume": 50,
+        "fullscreen": false,
+        "vsync": true,
+    };
+    assert_eq!(defaults.merged(&other, false), expected);
+    assert_eq!(defaults.merged(&other, true), expected);
+
+    // Inputs are unchanged.
+    assert_eq!(defaults.len(), 2);
+    assert_eq!(defaults.get("volume"), Some(50.to_variant()));
+    assert_eq!(user.len(), 2);
+}
+
 #[itest]
 fn dictionary_remove() {
     let mut dictionary = dict! {
@@ -399,6 +474,47 @@ fn dictionary_iter() {
     assert_eq!(map, map2);
 }
 
+#[itest]
+fn dictionary_iter_snapshot() {
+    let dictionary = dict! {
+        "foo": 1,
+        "bar": true,
+        "baz": "text",
+    };
+
+    let pairs: Vec<(Variant, Variant)> = dictionary.iter().collect();
+    assert_eq!(
+        pairs,
+        vec![
+            ("foo".to_variant(), 1.to_variant()),
+            ("bar".to_variant(), true.to_variant()),
+            ("baz".to_variant(), "text".to_variant()),
+        ]
+    );
+
+    assert_eq!(Dictionary::new().iter().count(), 0);
+}
+
+#[itest]
+fn dictionary_iter_snapshot_modified() {
+    let dictionary = dict! {
+        "foo": 1,
+        "bar": 2,
+        "baz": 3,
+    };
+
+    let mut dictionary_clone = dictionary.clone();
+    let mut iter = dictionary.iter();
+    assert_eq!(iter.next(), Some(("foo".to_variant(), 1.to_variant())));
+
+    // Removed keys are skipped, new keys are not visited.
+    dictionary_clone.remove("bar");
+    dictionary_clone.set("new", 4);
+
+    assert_eq!(iter.next(), Some(("baz".to_variant(), 3.to_variant())));
+    assert_eq!(iter.next(), None);
+}
+
 #[itest]
 fn dictionary_iter_size_hint() {
     // Test a completely empty dict.
@@ -8,7 +8,7 @@
 use crate::framework::{expect_panic, itest};
 use godot::builtin::{
     Color, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
-    PackedStringArray,
+    PackedInt64Array, PackedStringArray, PackedVector2Array, PackedVector3Array, Vector2, Vector3,
 };
 
 #[itest]
@@ -104,6 +104,15 @@ fn packed_array_to_vec() {
     assert_eq!(array.to_vec(), vec![1, 2]);
 }
 
+#[itest]
+fn packed_array_iter_str() {
+    let array = PackedStringArray::from(vec!["first".into(), "second".into(), "third".into()]);
+    let strings: Vec<String> = array.iter_str().collect();
+    assert_eq!(strings, ["first", "second", "third"]);
+
+    assert_eq!(PackedStringArray::new().iter_str().count(), 0);
+}
+
 /*
 #[itest(skip)]
 fn packed_array_into_iterator() {
@@ -170,6 +179,45 @@ fn packed_array_as_slice() {
     assert_eq!(empty.as_slice(), &[]);
 }
 
+#[itest]
+fn packed_array_as_slice_vectors() {
+    let points = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 1.0),
+    ];
+    let polygon = PackedVector2Array::from(points.clone());
+    assert_eq!(polygon.as_slice(), points.as_slice());
+
+    let vertices = vec![Vector3::new(1.0, 2.0, 3.0), Vector3::new(-4.0, 5.5, 0.0)];
+    let mesh = PackedVector3Array::from(vertices.clone());
+    assert_eq!(mesh.as_slice(), vertices.as_slice());
+
+    let sum: Vector3 = mesh.as_slice().iter().copied().sum();
+    assert_eq!(sum, Vector3::new(-3.0, 7.5, 3.0));
+}
+
+#[itest]
+fn packed_array_as_slice_ints() {
+    let ids: Vec<i32> = vec![-7, 0, 3, 42, 1000];
+    let array = PackedInt32Array::from(ids.as_slice());
+
+    assert_eq!(array.as_slice(), ids.as_slice());
+    assert_eq!(array.to_vec(), ids);
+    assert_eq!(array.as_slice().binary_search(&42), Ok(3));
+    assert_eq!(array.as_slice().binary_search(&5), Err(3));
+
+    let indices: Vec<i64> = vec![i64::MIN, -1, 0, i64::MAX];
+    let array = PackedInt64Array::from(indices.as_slice());
+    assert_eq!(array.as_slice(), indices.as_slice());
+
+    // Modifying a copy does not affect slices of the original.
+    let mut copy = array.clone();
+    copy.as_mut_slice()[0] = 5;
+    assert_eq!(array.as_slice(), indices.as_slice());
+    assert_eq!(copy.as_slice()[0], 5);
+}
+
 #[itest]
 fn packed_array_as_mut_slice() {
     let a = PackedByteArray::from(&[1, 2, 3]);
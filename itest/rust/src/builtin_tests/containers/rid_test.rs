@@ -22,6 +22,24 @@ fn rid_equiv() {
     assert_eq!(InnerRid::from_outer(&valid).get_id(), (10 << 32) | 20);
 }
 
+#[itest]
+fn rid_validity_and_id() {
+    let invalid = Rid::invalid();
+    assert!(!invalid.is_valid());
+    assert!(invalid.is_invalid());
+    assert_eq!(invalid, Rid::Invalid);
+    assert_eq!(invalid, Rid::new(0));
+    assert_eq!(invalid.to_u64(), 0);
+    assert_eq!(invalid.to_valid_u64(), None);
+
+    let id = (10 << 32) | 20;
+    let valid = Rid::new(id);
+    assert!(valid.is_valid());
+    assert_eq!(valid.to_u64(), id);
+    assert_eq!(valid.to_valid_u64(), Some(id));
+    assert_eq!(Rid::new(valid.to_u64()), valid);
+}
+
 #[itest]
 fn canvas_set_parent() {
     // This originally caused UB, but still testing it here in case it breaks.
@@ -11,6 +11,7 @@ use godot::builtin::{Callable, GString, Signal, StringName, Variant};
 use godot::meta::ToGodot;
 use godot::register::{godot_api, GodotClass};
 
+use godot::classes::object::ConnectFlags;
 use godot::classes::{Object, RefCounted};
 use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
 use godot::sys;
@@ -130,6 +131,47 @@ fn emit_signal() {
     receiver.free();
 }
 
+#[itest]
+#[cfg(since_api = "4.2")]
+fn emit_signal_typed() {
+    use godot::builtin::VariantType;
+    use std::sync::{Arc, Mutex};
+
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+
+    // Variant is not Send, so record type and string representation of each argument.
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_in_fn = received.clone();
+    signal.connect(
+        Callable::from_fn("record", move |args| {
+            let args = args
+                .iter()
+                .map(|arg| (arg.get_type(), arg.stringify().to_string()))
+                .collect::<Vec<_>>();
+            received_in_fn.lock().unwrap().push(args);
+            Ok(Variant::nil())
+        }),
+        0,
+    );
+
+    signal.emit_typed((42_i64, GString::from("hello")));
+    signal.emit_typed(());
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 2);
+    assert_eq!(
+        received[0],
+        vec![
+            (VariantType::INT, "42".to_string()),
+            (VariantType::STRING, "hello".to_string()),
+        ]
+    );
+    assert!(received[1].is_empty());
+}
+
 #[itest]
 fn connect_signal() {
     let mut object = RefCounted::new_gd();
@@ -149,3 +191,68 @@ fn connect_signal() {
 
     receiver.free();
 }
+
+#[itest]
+fn connect_signal_one_shot() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    let receiver = Receiver::new_alloc();
+
+    let connection = signal
+        .connect_ex(
+            Callable::from_object_method(&receiver, "receive_1_arg"),
+            ConnectFlags::ONE_SHOT,
+        )
+        .expect("connect one-shot");
+
+    assert!(connection.is_connected());
+    signal.emit(&[987i64.to_variant()]);
+    assert!(receiver.bind().used[1].get());
+
+    // One-shot connections are removed after the first emission.
+    receiver.bind().used[1].set(false);
+    assert!(!connection.is_connected());
+    signal.emit(&[987i64.to_variant()]);
+    assert!(!receiver.bind().used[1].get());
+
+    // Disconnecting an already-removed connection is a no-op.
+    connection.disconnect();
+
+    receiver.free();
+}
+
+#[itest]
+fn connect_signal_deferred() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal".into());
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    let receiver = Receiver::new_alloc();
+
+    let connection = signal
+        .connect_ex(
+            Callable::from_object_method(&receiver, "receive_0_arg"),
+            ConnectFlags::DEFERRED,
+        )
+        .expect("connect deferred");
+
+    assert_eq!(signal.connections().len(), 1);
+    assert_eq!(
+        *connection.callable(),
+        Callable::from_object_method(&receiver, "receive_0_arg")
+    );
+
+    // Deferred connections are invoked at idle time, not during emission.
+    signal.emit(&[]);
+    assert!(!receiver.bind().used[0].get());
+
+    connection.disconnect();
+    assert_eq!(signal.connections().len(), 0);
+
+    // Receiver must outlive the deferred call that was already queued.
+    receiver
+        .upcast::<Object>()
+        .call_deferred("free".into(), &[]);
+}
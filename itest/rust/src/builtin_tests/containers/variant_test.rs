@@ -6,6 +6,7 @@
  */
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt::Display;
 
 use godot::builtin::{
@@ -183,6 +184,120 @@ fn variant_get_type() {
     assert_eq!(variant.get_type(), VariantType::BASIS)
 }
 
+#[itest]
+fn variant_get_type_match() {
+    fn classify(variant: &Variant) -> &'static str {
+        match variant.get_type() {
+            VariantType::NIL => "nil",
+            VariantType::BOOL | VariantType::INT | VariantType::FLOAT => "scalar",
+            VariantType::STRING | VariantType::STRING_NAME | VariantType::NODE_PATH => "text",
+            VariantType::ARRAY | VariantType::DICTIONARY => "container",
+            VariantType::OBJECT => "object",
+            _ => "other",
+        }
+    }
+
+    assert_eq!(classify(&Variant::nil()), "nil");
+    assert_eq!(classify(&true.to_variant()), "scalar");
+    assert_eq!(classify(&74i32.to_variant()), "scalar");
+    assert_eq!(classify(&2.5f64.to_variant()), "scalar");
+    assert_eq!(classify(&gstr("hello").to_variant()), "text");
+    assert_eq!(classify(&NodePath::from("A/B").to_variant()), "text");
+    assert_eq!(classify(&varray![1, 2].to_variant()), "container");
+    assert_eq!(classify(&Dictionary::new().to_variant()), "container");
+    assert_eq!(classify(&TEST_BASIS.to_variant()), "other");
+
+    let node = Node::new_alloc();
+    assert_eq!(classify(&node.to_variant()), "object");
+    node.free();
+}
+
+#[itest]
+fn variant_duplicate_deep() {
+    let inventory = varray![1, 2];
+    let save = dict! { "inventory": inventory.clone() }.to_variant();
+
+    let copy = save.duplicate_deep();
+    assert_eq!(copy, save);
+
+    let mut nested = copy.to::<Dictionary>().at("inventory").to::<VariantArray>();
+    nested.push(3.to_variant());
+
+    // Nested array is independent of the original.
+    assert_eq!(inventory, varray![1, 2]);
+    assert_ne!(copy, save);
+}
+
+#[itest]
+fn variant_duplicate_shallow() {
+    let inventory = varray![1, 2];
+    let save = dict! { "inventory": inventory.clone() }.to_variant();
+
+    let copy = save.duplicate_shallow();
+
+    // Top level is independent...
+    let mut copy_dict = copy.to::<Dictionary>();
+    copy_dict.set("gold", 100);
+    assert!(!save.to::<Dictionary>().contains_key("gold"));
+
+    // ...but the nested array is shared.
+    let mut nested = copy_dict.at("inventory").to::<VariantArray>();
+    nested.push(3.to_variant());
+    assert_eq!(inventory, varray![1, 2, 3]);
+}
+
+#[itest]
+fn variant_duplicate_non_container() {
+    let values = [
+        Variant::nil(),
+        42.to_variant(),
+        gstr("text").to_variant(),
+        Vector3::new(1.0, 2.0, 3.0).to_variant(),
+    ];
+
+    for value in values {
+        assert_eq!(value.duplicate_deep(), value);
+        assert_eq!(value.duplicate_shallow(), value);
+    }
+
+    // Objects are shared, not duplicated.
+    let node = Node::new_alloc();
+    let variant = node.to_variant();
+    assert_eq!(variant.duplicate_deep().to::<Gd<Node>>(), node);
+    node.free();
+}
+
+#[itest]
+fn variant_option_roundtrip() {
+    let some = Variant::from_option(Some(5i64));
+    assert_eq!(some, 5i64.to_variant());
+    assert_eq!(some.try_to_option::<i64>().unwrap(), Some(5));
+
+    let none = Variant::from_option(None::<i64>);
+    assert!(none.is_nil());
+    assert_eq!(none.try_to_option::<i64>().unwrap(), None);
+
+    // Non-nil value of the wrong type.
+    let string = gstr("five").to_variant();
+    assert!(string.try_to_option::<i64>().is_err());
+}
+
+#[itest]
+fn variant_type_name() {
+    assert_eq!(Variant::nil().type_name(), "Nil");
+    assert_eq!(74i32.to_variant().type_name(), "int");
+    assert_eq!(2.5f64.to_variant().type_name(), "float");
+    assert_eq!(gstr("hello").to_variant().type_name(), "String");
+    assert_eq!(Vector2::new(1.0, 2.0).to_variant().type_name(), "Vector2");
+    assert_eq!(Dictionary::new().to_variant().type_name(), "Dictionary");
+    assert_eq!(varray![1, 2].to_variant().type_name(), "Array");
+    assert_eq!(TEST_BASIS.to_variant().type_name(), "Basis");
+
+    let node = Node::new_alloc();
+    assert_eq!(node.to_variant().type_name(), "Object");
+    node.free();
+}
+
 #[itest]
 fn variant_equal() {
     assert_eq!(Variant::nil(), ().to_variant());
@@ -469,6 +584,47 @@ fn variant_hash_correct() {
     assert_ne!(dict! { 0: dict! { 0: 0 } }, dict! { 0: dict! { 0: 1 } });
 }
 
+#[itest]
+fn variant_hash_equal_values() {
+    let pairs = [
+        (gstr("string").to_variant(), gstr("string").to_variant()),
+        (42.to_variant(), 42.to_variant()),
+        (
+            varray![1, "two"].to_variant(),
+            varray![1, "two"].to_variant(),
+        ),
+        (dict! { "a": 1 }.to_variant(), dict! { "a": 1 }.to_variant()),
+    ];
+
+    for (a, b) in pairs {
+        assert_eq!(a.hash_u32(), b.hash_u32(), "{a:?} vs. {b:?}");
+        assert_eq!(i64::from(a.hash_u32()), a.hash());
+    }
+}
+
+#[itest]
+fn variant_hash_cross_type() {
+    // Same text, different string types: Godot hashes both by content.
+    assert_eq!(
+        gstr("name").to_variant().hash_u32(),
+        StringName::from("name").to_variant().hash_u32()
+    );
+
+    // Numerically equal, but different types.
+    assert_ne!(1.to_variant().hash_u32(), 1.0.to_variant().hash_u32());
+
+    let set: HashSet<u32> = [
+        7.to_variant(),
+        gstr("seven").to_variant(),
+        1.5.to_variant(),
+        Vector2::new(7.0, 1.5).to_variant(),
+    ]
+    .iter()
+    .map(Variant::hash_u32)
+    .collect();
+    assert_eq!(set.len(), 4);
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
@@ -6,8 +6,8 @@
  */
 
 use crate::framework::{expect_panic, itest};
-use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{Quaternion, Vector3};
+use godot::builtin::math::{assert_eq_approx, ApproxEq};
+use godot::builtin::{real, Quaternion, Vector3};
 
 #[itest]
 fn quaternion_default() {
@@ -179,3 +179,21 @@ fn quaternion_spherical_cubic_interpolate_in_time() {
     assert_eq!(outcome, Quaternion::default())
 }
 // TODO more tests
+
+#[itest]
+fn quaternion_is_equal_approx() {
+    let a = Quaternion::new(0.2391, 0.099, 0.3696, 0.8924);
+    let within = Quaternion::new(0.2391 + 0.000_001, 0.099, 0.3696, 0.8924);
+    let outside = Quaternion::new(0.2391 + 0.001, 0.099, 0.3696, 0.8924);
+
+    assert!(a.approx_eq(&within));
+    assert!(!a.approx_eq(&outside));
+
+    // Same tolerance as Godot.
+    for b in [a, within, outside, Quaternion::default()] {
+        assert_eq!(a.approx_eq(&b), a.as_inner().is_equal_approx(b));
+    }
+
+    let tiny = real::EPSILON;
+    assert!(Quaternion::new(tiny, 0.0, 0.0, 1.0).approx_eq(&Quaternion::default()));
+}
@@ -8,6 +8,7 @@
 use crate::framework::itest;
 
 use godot::builtin::inner::InnerTransform3D;
+use godot::builtin::math::ApproxEq;
 use godot::builtin::{Aabb, Basis, Plane, Transform3D, VariantOperator, Vector3};
 use godot::meta::ToGodot;
 use godot::private::class_macros::assert_eq_approx;
@@ -82,3 +83,22 @@ fn transform3d_xform_equiv() {
         "operator: Transform3D * Plane"
     );
 }
+
+#[itest]
+fn transform3d_is_equal_approx() {
+    let inner = InnerTransform3D::from_outer(&TEST_TRANSFORM);
+
+    let within = TEST_TRANSFORM.translated(Vector3::new(0.000_001, 0.0, 0.0));
+    let outside = TEST_TRANSFORM.translated(Vector3::new(0.01, 0.0, 0.0));
+
+    assert!(TEST_TRANSFORM.approx_eq(&within));
+    assert!(!TEST_TRANSFORM.approx_eq(&outside));
+
+    // Same tolerance as Godot.
+    for other in [TEST_TRANSFORM, within, outside, Transform3D::IDENTITY] {
+        assert_eq!(
+            TEST_TRANSFORM.approx_eq(&other),
+            inner.is_equal_approx(other)
+        );
+    }
+}
@@ -50,3 +50,6 @@ mod convert_test;
 
 #[cfg(feature = "serde")]
 mod serde_test;
+
+#[cfg(feature = "camino")]
+mod camino_test;
@@ -6,6 +6,7 @@
  */
 
 use crate::framework::itest;
+use godot::builtin::strings::{deserialize_node_path_seq, serde_empty_as_none};
 use godot::builtin::{array, Array, Color, ColorHsv, GString, NodePath, StringName, Vector2i};
 use serde::{Deserialize, Serialize};
 
@@ -40,6 +41,65 @@ fn serde_node_path() {
     serde_roundtrip(&value, expected_json);
 }
 
+#[itest]
+fn serde_node_path_compact() {
+    let value = NodePath::from("/root/Main/Player:position:x");
+
+    let bytes = bincode::serialize(&value).unwrap();
+    let back: NodePath = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, value, "serde round-trip changes value");
+
+    // Compact formats store the raw string bytes, prefixed with their length (u64 in bincode).
+    let string = "/root/Main/Player:position:x";
+    let mut expected = (string.len() as u64).to_le_bytes().to_vec();
+    expected.extend_from_slice(string.as_bytes());
+    assert_eq!(bytes, expected);
+}
+
+#[itest]
+fn serde_node_path_seq_streaming() {
+    let json = r#"["/root/Main", "Player/Sprite:modulate", "", "../Sibling"]"#;
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+
+    let mut paths = Vec::new();
+    deserialize_node_path_seq(&mut deserializer, |path| paths.push(path)).unwrap();
+
+    let expected = ["/root/Main", "Player/Sprite:modulate", "", "../Sibling"].map(NodePath::from);
+    assert_eq!(paths, expected);
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#""not a sequence""#);
+    assert!(deserialize_node_path_seq(&mut deserializer, |_| {}).is_err());
+}
+
+#[itest]
+fn serde_node_path_empty_as_none() {
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Target {
+        #[serde(with = "serde_empty_as_none")]
+        path: Option<NodePath>,
+    }
+
+    let none = Target { path: None };
+    serde_roundtrip(&none, r#"{"path":null}"#);
+
+    let non_empty = Target {
+        path: Some(NodePath::from("../Player:position")),
+    };
+    serde_roundtrip(&non_empty, r#"{"path":"../Player:position"}"#);
+
+    // An empty path is written as null and therefore read back as None.
+    let empty = Target {
+        path: Some(NodePath::default()),
+    };
+    let json = serde_json::to_string(&empty).unwrap();
+    assert_eq!(json, r#"{"path":null}"#);
+    assert_eq!(serde_json::from_str::<Target>(&json).unwrap(), none);
+
+    // Empty strings written by other tools are treated the same way.
+    let back: Target = serde_json::from_str(r#"{"path":""}"#).unwrap();
+    assert_eq!(back, none);
+}
+
 #[itest]
 fn serde_string_name() {
     let value = StringName::from("hello world");
@@ -8,7 +8,11 @@
 use std::collections::HashSet;
 
 use crate::framework::itest;
-use godot::builtin::GString;
+use godot::builtin::strings::GStringBuilder;
+use godot::builtin::{GString, PackedFloat64Array, StringName, VariantOperator};
+use godot::global::Error as GodotError;
+use godot::meta::ToGodot;
+use godot::sys;
 
 // TODO use tests from godot-rust/gdnative
 
@@ -95,6 +99,31 @@ fn string_hash() {
     assert_eq!(set.len(), 5);
 }
 
+#[itest]
+fn string_hash_set_membership() {
+    let set: HashSet<GString> = ["Player", "Enemy", "player", ""]
+        .into_iter()
+        .map(GString::from)
+        .collect();
+
+    assert!(set.contains(&GString::from("Player")));
+    assert!(set.contains(&GString::from("player")));
+    assert!(set.contains(&GString::new()));
+    assert!(!set.contains(&GString::from("PLAYER")));
+    assert!(!set.contains(&GString::from("Enemy ")));
+}
+
+#[itest]
+fn string_hash_consistent_with_string_name() {
+    for s in ["", "Player", "emoji time: 😎"] {
+        let string = GString::from(s);
+        let name = StringName::from(s);
+
+        assert_eq!(string.hash(), name.hash(), "{s}");
+        assert_eq!(sys::hash_value(&string), sys::hash_value(&name), "{s}");
+    }
+}
+
 #[itest]
 fn string_with_null() {
     // Godot always ignores bytes after a null byte.
@@ -113,3 +142,315 @@ fn string_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn string_to_int() {
+    assert_eq!(GString::from("42").to_int(), 42);
+    assert_eq!(GString::from("42abc").to_int(), 42);
+    assert_eq!(GString::from("-17").to_int(), -17);
+    assert_eq!(GString::from("abc").to_int(), 0);
+    assert_eq!(GString::new().to_int(), 0);
+}
+
+#[itest]
+fn string_to_float() {
+    assert_eq!(GString::from("2.5").to_float(), 2.5);
+    assert_eq!(GString::from("-1.5").to_float(), -1.5);
+    assert_eq!(GString::from("-1.5xyz").to_float(), -1.5);
+    assert_eq!(GString::from("abc").to_float(), 0.0);
+}
+
+#[itest]
+fn string_split_floats() {
+    let floats = GString::from("1.0,2.5,3").split_floats(",");
+    assert_eq!(floats, PackedFloat64Array::from(&[1.0, 2.5, 3.0]));
+
+    let floats = GString::from("1,,3").split_floats(",");
+    assert_eq!(floats, PackedFloat64Array::from(&[1.0, 0.0, 3.0]));
+}
+
+#[itest]
+fn string_begins_ends_with() {
+    let string = GString::from("res://scenes/Player.tscn");
+
+    assert!(string.begins_with("res://"));
+    assert!(!string.begins_with("RES://"));
+    assert!(!string.begins_with("scenes"));
+    assert!(string.ends_with(".tscn"));
+    assert!(!string.ends_with(".TSCN"));
+    assert!(!string.ends_with("res://scenes/Player.tscn.import"));
+
+    // Empty prefix/suffix always matches, also on empty strings.
+    assert!(string.begins_with(""));
+    assert!(string.ends_with(""));
+    assert!(GString::new().begins_with(""));
+    assert!(GString::new().ends_with(""));
+}
+
+#[itest]
+fn string_contains() {
+    let string = GString::from("res://scenes/Player.tscn");
+
+    assert!(string.contains("scenes/"));
+    assert!(string.contains("res://scenes/Player.tscn"));
+    assert!(!string.contains("player"));
+    assert!(!string.contains("Enemy"));
+
+    // Godot does not find empty substrings.
+    assert!(!string.contains(""));
+}
+
+#[itest]
+fn string_left_right() {
+    let string = GString::from("Hello");
+
+    assert_eq!(string.left(2), GString::from("He"));
+    assert_eq!(string.left(-2), GString::from("Hel"));
+    assert_eq!(string.right(2), GString::from("lo"));
+    assert_eq!(string.right(-2), GString::from("llo"));
+
+    // Out of range.
+    assert_eq!(string.left(10), string);
+    assert_eq!(string.right(10), string);
+    assert_eq!(string.left(-10), GString::new());
+    assert_eq!(string.right(-10), GString::new());
+    assert_eq!(string.left(0), GString::new());
+
+    // Counts characters, not bytes.
+    assert_eq!(GString::from("ñandú").left(2), GString::from("ña"));
+}
+
+#[itest]
+fn string_substr() {
+    let string = GString::from("Hello, world");
+
+    assert_eq!(string.substr(7, 5), GString::from("world"));
+    assert_eq!(string.substr(0, 5), GString::from("Hello"));
+    assert_eq!(string.substr(7, -1), GString::from("world"));
+
+    // Clamped at the end.
+    assert_eq!(string.substr(7, 100), GString::from("world"));
+    assert_eq!(string.substr(12, 3), GString::new());
+}
+
+#[itest]
+fn string_join() {
+    let separator = GString::from(", ");
+
+    assert_eq!(
+        separator.join(["red", "green", "blue"]),
+        GString::from("red, green, blue")
+    );
+    assert_eq!(
+        separator.join(vec![String::from("a"), String::from("b")]),
+        GString::from("a, b")
+    );
+
+    // Single element: no separator.
+    assert_eq!(separator.join(["only"]), GString::from("only"));
+
+    // Empty iterator: empty string.
+    assert_eq!(separator.join(Vec::<&str>::new()), GString::new());
+
+    // Empty separator.
+    assert_eq!(GString::new().join(["a", "b", "c"]), GString::from("abc"));
+}
+
+#[itest]
+fn string_builder() {
+    let parts = ["alpha", "", "βeta", "γάμμα", "!"];
+
+    // Naive concatenation on the Godot side, one new string per part.
+    let mut naive = GString::new().to_variant();
+    let mut builder = GStringBuilder::new();
+    for part in parts {
+        naive = naive
+            .evaluate(&GString::from(part).to_variant(), VariantOperator::ADD)
+            .unwrap();
+        builder.push_str(part);
+    }
+
+    assert_eq!(builder.len(), parts.iter().map(|part| part.len()).sum());
+    assert_eq!(builder.build(), naive.to::<GString>());
+}
+
+#[itest]
+fn string_builder_write() {
+    use std::fmt::Write;
+
+    let mut builder = GStringBuilder::with_capacity(16);
+    assert!(builder.is_empty());
+
+    write!(builder, "{}-{}", 1, 2).unwrap();
+    builder.push('!');
+
+    assert_eq!(builder.build(), GString::from("1-2!"));
+    assert_eq!(GStringBuilder::new().build(), GString::new());
+}
+
+#[itest]
+fn string_path_join() {
+    let base = GString::from("res://assets");
+
+    assert_eq!(
+        base.path_join("textures/icon.png"),
+        GString::from("res://assets/textures/icon.png")
+    );
+
+    // No separator is added if one side already has it.
+    assert_eq!(
+        base.path_join("/icon.png"),
+        GString::from("res://assets/icon.png")
+    );
+    assert_eq!(
+        GString::from("res://assets/").path_join("icon.png"),
+        GString::from("res://assets/icon.png")
+    );
+
+    assert_eq!(
+        GString::new().path_join("icon.png"),
+        GString::from("icon.png")
+    );
+}
+
+#[itest]
+fn string_get_extension_basename() {
+    let path = GString::from("res://textures/icon.png");
+    assert_eq!(path.get_extension(), GString::from("png"));
+    assert_eq!(path.get_basename(), GString::from("res://textures/icon"));
+
+    // No extension.
+    let path = GString::from("res://LICENSE");
+    assert_eq!(path.get_extension(), GString::new());
+    assert_eq!(path.get_basename(), path);
+
+    // Multiple dots: only the last one counts.
+    let path = GString::from("user://backups/save.2024.tar.gz");
+    assert_eq!(path.get_extension(), GString::from("gz"));
+    assert_eq!(
+        path.get_basename(),
+        GString::from("user://backups/save.2024.tar")
+    );
+
+    // Dots in directory names are ignored.
+    let path = GString::from("res://v1.2/readme");
+    assert_eq!(path.get_extension(), GString::new());
+    assert_eq!(path.get_basename(), path);
+}
+
+#[itest]
+fn string_similarity() {
+    let string = GString::from("hello");
+
+    assert_eq!(string.similarity("hello"), 1.0);
+    assert_eq!(string.similarity("xyz"), 0.0);
+
+    // Bigrams "he el ll lo" vs. "ha al ll lo": 2 * 2 shared / 8 total.
+    assert_eq!(string.similarity("hallo"), 0.5);
+
+    // Case-sensitive.
+    assert!(string.similarity("HELLO") < 0.01);
+
+    // Too short for bigrams.
+    assert_eq!(GString::from("a").similarity("a"), 1.0);
+    assert_eq!(GString::from("a").similarity("ab"), 0.0);
+}
+
+#[itest]
+fn string_case_conversion() {
+    let string = GString::from("MyHTTPServer");
+
+    assert_eq!(string.to_snake_case(), GString::from("my_http_server"));
+    assert_eq!(string.to_camel_case(), GString::from("myHttpServer"));
+    assert_eq!(string.to_pascal_case(), GString::from("MyHttpServer"));
+
+    assert_eq!(
+        GString::from("player_max_health").to_camel_case(),
+        GString::from("playerMaxHealth")
+    );
+}
+
+#[itest]
+fn string_case_roundtrip() {
+    let snake = GString::from("node_path_cache");
+
+    let pascal = snake.to_pascal_case();
+    assert_eq!(pascal, GString::from("NodePathCache"));
+    assert_eq!(pascal.to_snake_case(), snake);
+    assert_eq!(snake.to_camel_case().to_snake_case(), snake);
+}
+
+#[itest]
+fn string_xml_escape() {
+    let string = GString::from(r#"<tag>&"'"#);
+
+    let escaped = string.xml_escape(true);
+    assert_eq!(escaped, GString::from("&lt;tag&gt;&amp;&quot;&apos;"));
+    assert_eq!(escaped.xml_unescape(), string);
+
+    // Quotes are kept unless requested.
+    assert_eq!(
+        string.xml_escape(false),
+        GString::from(r#"&lt;tag&gt;&amp;"'"#)
+    );
+}
+
+#[itest]
+fn string_uri_encode() {
+    let string = GString::from("Godot Engine: 'docs'");
+    let encoded = string.uri_encode();
+    assert_eq!(encoded, GString::from("Godot%20Engine%3A%20%27docs%27"));
+    assert_eq!(encoded.uri_decode(), string);
+
+    // Non-ASCII characters are encoded as UTF-8 bytes.
+    let string = GString::from("café ü");
+    let encoded = string.uri_encode();
+    assert_eq!(encoded, GString::from("caf%C3%A9%20%C3%BC"));
+    assert_eq!(encoded.uri_decode(), string);
+
+    // Unreserved characters are kept.
+    let string = GString::from("A-z_0.9~");
+    assert_eq!(string.uri_encode(), string);
+}
+
+#[itest]
+fn string_regex_match() {
+    let string = GString::from("released on 2024-07-15");
+
+    let found = string
+        .regex_match(r"(?<year>\d{4})-(\d{2})-(\d{2})")
+        .expect("valid pattern")
+        .expect("pattern matches");
+
+    assert_eq!(found.get_string(), GString::from("2024-07-15"));
+    assert_eq!(
+        found.get_string_ex().name("year".to_variant()).done(),
+        GString::from("2024")
+    );
+    assert_eq!(
+        found.get_string_ex().name(3.to_variant()).done(),
+        GString::from("15")
+    );
+    assert!(found.get_names().contains_key("year"));
+}
+
+#[itest]
+fn string_regex_match_none() {
+    let string = GString::from("no digits here");
+
+    let found = string.regex_match(r"\d+").expect("valid pattern");
+    assert!(found.is_none());
+}
+
+#[itest]
+fn string_regex_match_invalid_pattern() {
+    let string = GString::from("anything");
+
+    // Godot prints an error for the invalid pattern; this is expected.
+    let err = string
+        .regex_match("(unclosed")
+        .expect_err("invalid pattern must be rejected");
+
+    assert_eq!(err.pattern(), "(unclosed");
+    assert_ne!(err.godot_error(), GodotError::OK);
+}
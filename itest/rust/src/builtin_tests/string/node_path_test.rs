@@ -5,10 +5,21 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::framework::itest;
-use godot::builtin::{GString, NodePath};
+#[cfg(since_api = "4.2")]
+use crate::framework::TestContext;
+use crate::framework::{expect_panic, itest};
+use godot::builtin::strings::{
+    coerce_to_node_path, CaseInsensitiveNodePath, HashedNodePath, NodePathComponent, NodePathKey,
+    NodePathVisitor, PropertyPath, StrictNodePathBuilder,
+};
+use godot::builtin::{GString, NodePath, StringName, Variant};
+use godot::classes::Node;
+use godot::meta::error::{ComponentError, NodePathDecodeError, NodePathError};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewAlloc};
+use godot::sys;
 
 #[itest]
 fn node_path_default() {
@@ -65,6 +76,44 @@ fn node_path_hash() {
     assert_eq!(set.len(), 5);
 }
 
+#[itest]
+fn node_path_collect_unique() {
+    let unique = NodePath::collect_unique(["A/B", "A/B"].map(NodePath::from));
+    assert_eq!(unique.len(), 1);
+    assert!(unique.contains(&NodePath::from("A/B")));
+
+    let unique = NodePath::collect_unique(
+        [
+            "/root/Main",
+            "root/Main",
+            "Main:prop",
+            "Main:prop",
+            "Main/./Child",
+            "Main/Child",
+        ]
+        .map(NodePath::from),
+    );
+    assert_eq!(unique.len(), 5);
+
+    assert!(NodePath::collect_unique([]).is_empty());
+}
+
+#[itest]
+fn node_path_hash_map_borrowed_lookup() {
+    let mut map = HashMap::new();
+    map.insert(NodePath::from("Parent/Child"), 1);
+    map.insert(NodePath::from("Parent/Child:position"), 2);
+
+    // Separately constructed path, looked up by reference without being moved into the map.
+    let query = NodePath::from("Parent/Child");
+    assert_eq!(map.get(&query), Some(&1));
+    assert!(map.contains_key(&NodePath::from("Parent/Child:position")));
+    assert!(!map.contains_key(&NodePath::from("Parent")));
+
+    // Query is still usable afterwards.
+    assert_eq!(query, NodePath::from("Parent/Child"));
+}
+
 #[itest]
 fn node_path_with_null() {
     // Godot always ignores bytes after a null byte.
@@ -83,3 +132,1580 @@ fn node_path_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn node_path_is_property_path() {
+    assert!(NodePath::from("Node:prop").is_property_path());
+    assert!(NodePath::from("Parent/Node:prop:sub").is_property_path());
+    assert!(NodePath::from(":prop").is_property_path());
+
+    assert!(!NodePath::from("Node").is_property_path());
+    assert!(!NodePath::from("Parent/Node").is_property_path());
+    assert!(!NodePath::default().is_property_path());
+}
+
+#[itest]
+fn node_path_iter_components() {
+    let path = NodePath::from("Parent/Child:position:x");
+    let components: Vec<_> = path.iter_components().collect();
+
+    assert_eq!(
+        components,
+        [
+            NodePathComponent::Name("Parent".into()),
+            NodePathComponent::Name("Child".into()),
+            NodePathComponent::Subname("position".into()),
+            NodePathComponent::Subname("x".into()),
+        ]
+    );
+
+    assert_eq!(NodePath::default().iter_components().count(), 0);
+}
+
+#[itest]
+fn node_path_iter_components_roundtrip() {
+    let cases = [
+        "Node",
+        "Parent/Child",
+        "Parent/Child:position:x",
+        "/root/Main",
+        ":prop",
+        "../Sibling:modulate",
+    ];
+
+    for case in cases {
+        let path = NodePath::from(case);
+
+        let mut reconstructed = String::new();
+        if path.as_inner().is_absolute() {
+            reconstructed.push('/');
+        }
+
+        for (i, component) in path.iter_components().enumerate() {
+            match component {
+                NodePathComponent::Name(name) => {
+                    if i > 0 {
+                        reconstructed.push('/');
+                    }
+                    reconstructed.push_str(&name.to_string());
+                }
+                NodePathComponent::Subname(subname) => {
+                    reconstructed.push(':');
+                    reconstructed.push_str(&subname.to_string());
+                }
+            }
+        }
+
+        assert_eq!(reconstructed, case);
+    }
+}
+
+#[itest]
+fn node_path_matches_glob_single_star() {
+    let path = NodePath::from("Player/Sprite");
+
+    assert!(path.matches_glob("*/Sprite"));
+    assert!(path.matches_glob("Player/*"));
+    assert!(path.matches_glob("*/*"));
+
+    assert!(!path.matches_glob("*"));
+    assert!(!path.matches_glob("*/*/*"));
+    assert!(!NodePath::from("A/Player/Sprite").matches_glob("*/Sprite"));
+}
+
+#[itest]
+fn node_path_matches_glob_double_star() {
+    let path = NodePath::from("A/B/C/Leaf");
+
+    assert!(path.matches_glob("A/**/Leaf"));
+    assert!(path.matches_glob("**/Leaf"));
+    assert!(path.matches_glob("**"));
+    assert!(path.matches_glob("A/**"));
+    assert!(path.matches_glob("**/B/**/Leaf"));
+
+    // `**` also matches zero components.
+    assert!(NodePath::from("A/Leaf").matches_glob("A/**/Leaf"));
+
+    assert!(!path.matches_glob("B/**/Leaf"));
+    assert!(!path.matches_glob("A/**/Other"));
+}
+
+#[itest]
+fn node_path_matches_glob_literal() {
+    let path = NodePath::from("A/B");
+
+    assert!(path.matches_glob("A/B"));
+    assert!(!path.matches_glob("A/C"));
+    assert!(!path.matches_glob("A"));
+
+    // Absoluteness must match.
+    assert!(NodePath::from("/root/A").matches_glob("/root/A"));
+    assert!(!NodePath::from("/root/A").matches_glob("root/A"));
+    assert!(!NodePath::from("root/A").matches_glob("/root/A"));
+}
+
+#[itest]
+fn node_path_matches_glob_subnames() {
+    let path = NodePath::from("Node/Sprite:modulate:a");
+
+    // Subnames are ignored unless the pattern contains `:`.
+    assert!(path.matches_glob("Node/Sprite"));
+    assert!(path.matches_glob("**/Sprite:modulate:a"));
+    assert!(path.matches_glob("**/Sprite:modulate:*"));
+    assert!(path.matches_glob("**:**"));
+
+    assert!(!path.matches_glob("**/Sprite:modulate"));
+    assert!(!path.matches_glob("**/Sprite:position:*"));
+}
+
+#[itest]
+fn node_path_truncate_subnames() {
+    let path = NodePath::from("Node:a:b:c");
+
+    assert_eq!(path.truncate_subnames(1), NodePath::from("Node:a"));
+    assert_eq!(path.truncate_subnames(2), NodePath::from("Node:a:b"));
+
+    // No-op if `keep` exceeds the subname count.
+    assert_eq!(path.truncate_subnames(3), path);
+    assert_eq!(path.truncate_subnames(10), path);
+
+    // `keep == 0` drops all subnames.
+    assert_eq!(path.truncate_subnames(0), NodePath::from("Node"));
+    assert_eq!(
+        NodePath::from("/root/Node:a").truncate_subnames(0),
+        NodePath::from("/root/Node")
+    );
+}
+
+#[itest]
+fn node_path_replace_subname() {
+    let path = NodePath::from("/root/Node:position:x");
+
+    assert_eq!(
+        path.replace_subname(1, &StringName::from("y")),
+        NodePath::from("/root/Node:position:y")
+    );
+    assert_eq!(
+        path.replace_subname(0, &StringName::from("scale")),
+        NodePath::from("/root/Node:scale:x")
+    );
+
+    expect_panic("subname index out of bounds", || {
+        path.replace_subname(2, &StringName::from("z"));
+    });
+}
+
+#[itest]
+fn node_path_splice_subnames_grow() {
+    let path = NodePath::from("Node:material:r");
+    let replacement = [StringName::from("surface"), StringName::from("albedo")];
+
+    assert_eq!(
+        path.splice_subnames(0..1, &replacement),
+        NodePath::from("Node:surface:albedo:r")
+    );
+
+    // Empty range inserts.
+    assert_eq!(
+        path.splice_subnames(2..2, &replacement),
+        NodePath::from("Node:material:r:surface:albedo")
+    );
+}
+
+#[itest]
+fn node_path_splice_subnames_shrink() {
+    let path = NodePath::from("/A/B:one:two:three:four");
+
+    assert_eq!(
+        path.splice_subnames(1..4, &[StringName::from("rest")]),
+        NodePath::from("/A/B:one:rest")
+    );
+    assert_eq!(path.splice_subnames(0..4, &[]), NodePath::from("/A/B"));
+
+    expect_panic("subname range out of bounds", || {
+        path.splice_subnames(3..5, &[]);
+    });
+}
+
+#[itest]
+fn node_path_without_subnames() {
+    assert_eq!(
+        NodePath::from("Parent/Node:position:x").without_subnames(),
+        NodePath::from("Parent/Node")
+    );
+    assert_eq!(
+        NodePath::from("Parent/Node").without_subnames(),
+        NodePath::from("Parent/Node")
+    );
+    assert_eq!(
+        NodePath::from(":prop").without_subnames(),
+        NodePath::default()
+    );
+}
+
+#[itest]
+fn node_path_concat_str() {
+    let path = NodePath::from("Parent");
+
+    // Plain name.
+    assert_eq!(path.concat_str("Child"), NodePath::from("Parent/Child"));
+
+    // Multi-component tail.
+    assert_eq!(
+        path.concat_str("Child/Grandchild"),
+        NodePath::from("Parent/Child/Grandchild")
+    );
+
+    // Subname-bearing tail.
+    assert_eq!(
+        path.concat_str("Child:position:x"),
+        NodePath::from("Parent/Child:position:x")
+    );
+
+    // Absolute flag and existing subnames are preserved.
+    assert_eq!(
+        NodePath::from("/root").concat_str("Main"),
+        NodePath::from("/root/Main")
+    );
+    assert_eq!(
+        NodePath::from("Node:material").concat_str("Child:albedo"),
+        NodePath::from("Node/Child:material:albedo")
+    );
+
+    // Empty base.
+    assert_eq!(
+        NodePath::default().concat_str("Child"),
+        NodePath::from("Child")
+    );
+}
+
+#[itest]
+fn node_path_hashed() {
+    let path = NodePath::from("Parent/Child:position");
+    let hashed = HashedNodePath::from(path.clone());
+
+    assert_eq!(hashed.hash_value(), path.hash());
+    assert_eq!(sys::hash_value(&hashed), sys::hash_value(&path));
+
+    // Deref to NodePath.
+    assert!(hashed.is_property_path());
+    assert_eq!(*hashed, path);
+
+    let map: HashMap<HashedNodePath, i32> = [("A", 1), ("A/B", 2), ("A/B:c", 3)]
+        .into_iter()
+        .map(|(path, value)| (HashedNodePath::from(NodePath::from(path)), value))
+        .collect();
+
+    assert_eq!(
+        map.get(&HashedNodePath::from(NodePath::from("A/B"))),
+        Some(&2)
+    );
+    assert_eq!(map.get(&HashedNodePath::from(NodePath::from("A/C"))), None);
+}
+
+#[itest]
+fn node_path_key() {
+    let path = NodePath::from("Parent/Child:position");
+    let key = NodePathKey::new(path.clone());
+
+    assert_eq!(key.hash_value(), path.hash());
+    assert_eq!(sys::hash_value(&key), sys::hash_value(&path));
+    assert_eq!(key.as_str(), "Parent/Child:position");
+    assert_eq!(key.to_string(), path.to_string());
+
+    // Eq agrees with NodePath.
+    assert_eq!(
+        key,
+        NodePathKey::new(NodePath::from("Parent/Child:position"))
+    );
+    assert_ne!(key, NodePathKey::new(NodePath::from("Parent/Child")));
+    assert_eq!(*key, path);
+
+    let map: HashMap<NodePathKey, i32> = [("A", 1), ("A/B", 2), ("A/B:c", 3)]
+        .into_iter()
+        .map(|(path, value)| (NodePathKey::new(NodePath::from(path)), value))
+        .collect();
+
+    assert_eq!(map.get(&NodePathKey::new(NodePath::from("A/B"))), Some(&2));
+    assert_eq!(map.get(&NodePathKey::new(NodePath::from("A/C"))), None);
+}
+
+#[itest]
+fn node_path_try_new_valid() {
+    let cases = [
+        "",
+        "/",
+        "Node",
+        "/root/Main/Player",
+        "../Sibling",
+        "./Child",
+        "%UniqueNode/Child",
+        "Sprite:material:shader_parameter/albedo",
+        ":position:x",
+    ];
+
+    for case in cases {
+        let path = NodePath::try_new(case).unwrap_or_else(|err| panic!("{case}: {err}"));
+        assert_eq!(path, NodePath::from(case));
+    }
+}
+
+#[itest]
+fn node_path_try_new_invalid() {
+    assert_eq!(
+        NodePath::try_new("Node:"),
+        Err(NodePathError::EmptySubname { index: 0 })
+    );
+    assert_eq!(
+        NodePath::try_new("Node:a::b"),
+        Err(NodePathError::EmptySubname { index: 1 })
+    );
+    assert_eq!(
+        NodePath::try_new("A//B"),
+        Err(NodePathError::EmptyName { index: 1 })
+    );
+    assert_eq!(
+        NodePath::try_new("A/B/"),
+        Err(NodePathError::EmptyName { index: 2 })
+    );
+    assert_eq!(
+        NodePath::try_new("Scene.tscn/Node"),
+        Err(NodePathError::IllegalCharacter {
+            component: "Scene.tscn".to_string(),
+            character: '.',
+        })
+    );
+    assert_eq!(
+        NodePath::try_new("A/B%C"),
+        Err(NodePathError::IllegalCharacter {
+            component: "B%C".to_string(),
+            character: '%',
+        })
+    );
+    assert_eq!(
+        NodePath::try_new("A\0B"),
+        Err(NodePathError::IllegalCharacter {
+            component: "A\0B".to_string(),
+            character: '\0',
+        })
+    );
+}
+
+#[itest]
+fn node_path_try_to_string() {
+    let path = NodePath::from("/root/Über/Ñode:position:x");
+    assert_eq!(
+        path.try_to_string(),
+        Ok("/root/Über/Ñode:position:x".to_string())
+    );
+
+    let path = NodePath::from("A/B\u{FFFD}");
+    let err = path.try_to_string().expect_err("replacement character");
+    assert_eq!(err.position(), 3);
+}
+
+#[itest]
+fn node_path_slice_names() {
+    let path = NodePath::from("A/B/C/D");
+    assert_eq!(path.slice_names(1..3), NodePath::from("B/C"));
+    assert_eq!(path.slice_names(1..=3), NodePath::from("B/C/D"));
+    assert_eq!(path.slice_names(..2), NodePath::from("A/B"));
+    assert_eq!(path.slice_names(2..), NodePath::from("C/D"));
+    assert_eq!(path.slice_names(..), path);
+
+    // Empty range.
+    assert_eq!(path.slice_names(2..2), NodePath::default());
+    assert_eq!(path.slice_names(4..), NodePath::default());
+}
+
+#[itest]
+fn node_path_slice_names_absolute_and_subnames() {
+    let path = NodePath::from("/root/Main/Player:position:x");
+    assert_eq!(path.slice_names(..), path);
+    assert_eq!(path.slice_names(..2), NodePath::from("/root/Main"));
+    assert_eq!(
+        path.slice_names(1..),
+        NodePath::from("Main/Player:position:x")
+    );
+    assert_eq!(path.slice_names(1..2), NodePath::from("Main"));
+}
+
+#[itest]
+fn node_path_slice_names_out_of_bounds() {
+    let path = NodePath::from("A/B");
+    expect_panic("end beyond name count", || {
+        path.slice_names(1..3);
+    });
+}
+
+#[itest]
+fn property_path_try_from() {
+    let node_only = NodePath::from("Parent/Child");
+    assert_eq!(
+        PropertyPath::try_from(node_only),
+        Err(NodePathError::NoSubnames)
+    );
+    assert_eq!(
+        PropertyPath::try_from(NodePath::default()),
+        Err(NodePathError::NoSubnames)
+    );
+
+    let property = PropertyPath::try_from(NodePath::from("Parent/Child:position:x")).unwrap();
+    assert_eq!(property.as_inner().get_subname_count(), 2);
+    assert_eq!(*property, NodePath::from("Parent/Child:position:x"));
+    assert_eq!(
+        NodePath::from(property),
+        NodePath::from("Parent/Child:position:x")
+    );
+}
+
+#[itest]
+fn property_path_from_node_path() {
+    let property = NodePath::from("A/B:c").to_property_path().unwrap();
+    assert_eq!(property.into_inner(), NodePath::from(":A/B:c"));
+
+    assert_eq!(
+        NodePath::default().to_property_path(),
+        Err(NodePathError::NoSubnames)
+    );
+}
+
+#[itest]
+fn node_path_dedup_adjacent_names() {
+    let path = NodePath::from("A/A/B/B/B/C");
+    assert_eq!(path.dedup_adjacent_names(), NodePath::from("A/B/C"));
+
+    // Only adjacent duplicates are collapsed; absolute flag and subnames are kept.
+    let path = NodePath::from("/A/B/B/A:x:x");
+    assert_eq!(path.dedup_adjacent_names(), NodePath::from("/A/B/A:x:x"));
+
+    // No duplicates: identity.
+    let path = NodePath::from("/root/Main/Player:position");
+    assert_eq!(path.dedup_adjacent_names(), path);
+
+    // Only duplicates.
+    let path = NodePath::from("Node/Node/Node");
+    assert_eq!(path.dedup_adjacent_names(), NodePath::from("Node"));
+}
+
+#[itest]
+fn node_path_with_absolute() {
+    let path = NodePath::from("A/B:prop");
+    let absolute = path.with_absolute(true);
+    assert_eq!(absolute, NodePath::from("/A/B:prop"));
+    assert!(absolute.as_inner().is_absolute());
+
+    let relative = absolute.with_absolute(false);
+    assert_eq!(relative, path);
+    assert!(!relative.as_inner().is_absolute());
+}
+
+#[itest]
+fn node_path_with_absolute_unchanged() {
+    let path = NodePath::from("/A/B");
+    assert_eq!(path.with_absolute(true), path);
+
+    let path = NodePath::from("A/B");
+    assert_eq!(path.with_absolute(false), path);
+
+    // Root path loses its only component.
+    assert_eq!(
+        NodePath::from("/").with_absolute(false),
+        NodePath::default()
+    );
+}
+
+#[itest]
+fn node_path_try_get_name() {
+    let path = NodePath::from("/root/Main:position:x");
+
+    assert_eq!(path.try_get_name(0), Some(StringName::from("root")));
+    assert_eq!(path.try_get_name(1), Some(StringName::from("Main")));
+    assert_eq!(path.try_get_name(2), None);
+    assert_eq!(path.try_get_name(usize::MAX), None);
+
+    assert_eq!(path.try_get_subname(0), Some(StringName::from("position")));
+    assert_eq!(path.try_get_subname(1), Some(StringName::from("x")));
+    assert_eq!(path.try_get_subname(2), None);
+
+    assert_eq!(NodePath::default().try_get_name(0), None);
+    assert_eq!(NodePath::default().try_get_subname(0), None);
+}
+
+#[itest]
+fn node_path_get_name() {
+    let path = NodePath::from("A/B:prop");
+
+    assert_eq!(path.get_name(1), StringName::from("B"));
+    assert_eq!(path.get_subname(0), StringName::from("prop"));
+
+    expect_panic("name index out of bounds", || {
+        path.get_name(2);
+    });
+    expect_panic("subname index out of bounds", || {
+        path.get_subname(1);
+    });
+}
+
+#[itest]
+fn node_path_align_depth_pad() {
+    let filler = StringName::from("_");
+
+    assert_eq!(
+        NodePath::from("A/B").align_depth(4, &filler),
+        NodePath::from("A/B/_/_")
+    );
+    assert_eq!(
+        NodePath::from("/A:prop").align_depth(2, &filler),
+        NodePath::from("/A/_:prop")
+    );
+    assert_eq!(
+        NodePath::default().align_depth(2, &filler),
+        NodePath::from("_/_")
+    );
+
+    // Already at target depth.
+    let path = NodePath::from("/A/B:prop");
+    assert_eq!(path.align_depth(2, &filler), path);
+}
+
+#[itest]
+fn node_path_align_depth_trim() {
+    let filler = StringName::from("_");
+    let path = NodePath::from("/A/B/C:prop");
+
+    assert_eq!(path.align_depth(2, &filler), NodePath::from("/A/B"));
+    assert_eq!(path.align_depth(0, &filler), NodePath::default());
+    assert_eq!(
+        NodePath::from("A/B/C").align_depth(1, &filler),
+        NodePath::from("A")
+    );
+}
+
+#[itest]
+fn node_path_simplified() {
+    assert_eq!(
+        NodePath::from("Foo/./Bar").simplified(),
+        NodePath::from("Foo/Bar")
+    );
+    assert_eq!(
+        NodePath::from("/root/A/../B:prop").simplified(),
+        NodePath::from("/root/B:prop")
+    );
+
+    // Unresolvable parent references are kept.
+    let path = NodePath::from("../Sibling");
+    assert_eq!(path.simplified(), path);
+
+    // Names that cancel out completely leave the current node.
+    assert_eq!(NodePath::from("A/..").simplified(), NodePath::from("."));
+}
+
+#[itest]
+fn node_path_simplified_idempotent() {
+    for path in [
+        "Foo/./Bar",
+        "A/B/../../C/./D:x",
+        "../../A/B/..",
+        "/root/Main",
+    ] {
+        let once = NodePath::from(path).simplified();
+        assert_eq!(once.simplified(), once, "path: {path}");
+    }
+}
+
+#[itest]
+fn node_path_from_name_slice() {
+    let path = NodePath::from(&["A", "B", "C"][..]);
+    assert_eq!(path, NodePath::from("A/B/C"));
+    assert_eq!(path.as_inner().get_name_count(), 3);
+
+    let empty: &[&str] = &[];
+    assert_eq!(NodePath::from(empty), NodePath::default());
+    assert!(NodePath::from(empty).is_empty());
+}
+
+#[itest]
+fn node_path_from_name_vec() {
+    let names = vec!["Level".to_string(), "Player".to_string()];
+    assert_eq!(NodePath::from(names), NodePath::from("Level/Player"));
+
+    assert_eq!(NodePath::from(Vec::<String>::new()), NodePath::default());
+}
+
+#[itest]
+fn node_path_first_last_name() {
+    let empty = NodePath::default();
+    assert_eq!(empty.first_name(), None);
+    assert_eq!(empty.last_name(), None);
+
+    // Only subnames, no names.
+    let property = NodePath::from(":position:x");
+    assert_eq!(property.first_name(), None);
+    assert_eq!(property.last_name(), None);
+
+    let single = NodePath::from("Player");
+    assert_eq!(single.first_name(), Some(StringName::from("Player")));
+    assert_eq!(single.last_name(), Some(StringName::from("Player")));
+
+    let multi = NodePath::from("/root/Main/Player:position");
+    assert_eq!(multi.first_name(), Some(StringName::from("root")));
+    assert_eq!(multi.last_name(), Some(StringName::from("Player")));
+}
+
+#[itest]
+fn node_path_relative_to() {
+    let base = NodePath::from("/root/Level/Enemies");
+
+    // Descendant.
+    let path = NodePath::from("/root/Level/Enemies/Goblin/Sprite");
+    assert_eq!(
+        path.relative_to(&base),
+        Some(NodePath::from("Goblin/Sprite"))
+    );
+
+    // Sibling, with subnames.
+    let path = NodePath::from("/root/Level/Player:position:x");
+    assert_eq!(
+        path.relative_to(&base),
+        Some(NodePath::from("../Player:position:x"))
+    );
+
+    // Unrelated subtree.
+    let path = NodePath::from("/root/Hud/Health");
+    assert_eq!(
+        path.relative_to(&base),
+        Some(NodePath::from("../../Hud/Health"))
+    );
+
+    // Ancestor and same node.
+    assert_eq!(
+        NodePath::from("/root").relative_to(&base),
+        Some(NodePath::from("../.."))
+    );
+    assert_eq!(base.relative_to(&base), Some(NodePath::from(".")));
+
+    // Relative inputs.
+    assert_eq!(NodePath::from("Goblin").relative_to(&base), None);
+    assert_eq!(base.relative_to(&NodePath::from("Level")), None);
+}
+
+#[itest]
+fn node_path_relative_to_matches_get_path_to() {
+    let mut root = Node::new_alloc();
+    root.set_name("Root".into());
+
+    let mut level = Node::new_alloc();
+    level.set_name("Level".into());
+    root.add_child(level.clone());
+
+    let mut enemies = Node::new_alloc();
+    enemies.set_name("Enemies".into());
+    level.add_child(enemies.clone());
+
+    let mut hud = Node::new_alloc();
+    hud.set_name("Hud".into());
+    root.add_child(hud.clone());
+
+    // Nodes outside the tree have no absolute path, so emulate one relative to the root.
+    let absolute = |node: &Gd<Node>| {
+        if *node == root {
+            NodePath::from("/Root")
+        } else {
+            NodePath::from(format!("/Root/{}", root.get_path_to(node.clone())))
+        }
+    };
+    let base = absolute(&enemies);
+
+    for target in [&root, &level, &enemies, &hud] {
+        assert_eq!(
+            absolute(target).relative_to(&base),
+            Some(enemies.get_path_to(target.clone())),
+            "target {target:?}"
+        );
+    }
+
+    root.free();
+}
+
+#[itest]
+fn node_path_char_and_bytes_len() {
+    let ascii = NodePath::from("/root/Main:position");
+    assert_eq!(ascii.char_len(), 19);
+    assert_eq!(ascii.bytes_len(), 19);
+
+    // "Ü" and "é" take 2 bytes in UTF-8, "😎" takes 4.
+    let multi_byte = NodePath::from("Über/Café/😎");
+    assert_eq!(multi_byte.char_len(), 11);
+    assert_eq!(multi_byte.bytes_len(), 16);
+    assert_eq!(multi_byte.bytes_len(), multi_byte.to_string().len());
+
+    assert_eq!(NodePath::default().char_len(), 0);
+    assert_eq!(NodePath::default().bytes_len(), 0);
+}
+
+#[itest]
+fn node_path_case_insensitive() {
+    let insensitive = |s: &str| CaseInsensitiveNodePath::from(NodePath::from(s));
+
+    let a = insensitive("/Root/Player:Position");
+    let b = insensitive("/root/PLAYER:position");
+    assert_eq!(a, b);
+    assert_eq!(sys::hash_value(&a), sys::hash_value(&b));
+
+    // Unicode-aware, not only ASCII.
+    assert_eq!(insensitive("Über/Ñode"), insensitive("über/ñODE"));
+
+    // Genuinely different paths.
+    assert_ne!(a, insensitive("/Root/Enemy:Position"));
+    assert_ne!(
+        a,
+        insensitive("Root/Player:Position"),
+        "absolute vs. relative"
+    );
+    assert_ne!(
+        insensitive("Root/Player"),
+        insensitive("Root:Player"),
+        "name vs. subname"
+    );
+
+    // Original case is kept.
+    assert_eq!(*a, NodePath::from("/Root/Player:Position"));
+
+    let mut set = HashSet::new();
+    set.insert(a);
+    assert!(set.contains(&b));
+    assert!(!set.contains(&insensitive("/Root/Enemy")));
+}
+
+#[itest]
+fn node_path_prepend() {
+    let path = NodePath::from("Player/Sprite:modulate");
+
+    assert_eq!(
+        path.prepend(&NodePath::from("/root/Level")),
+        Some(NodePath::from("/root/Level/Player/Sprite:modulate"))
+    );
+    assert_eq!(
+        path.prepend(&NodePath::from("Level")),
+        Some(NodePath::from("Level/Player/Sprite:modulate"))
+    );
+
+    // Subnames of the prefix are dropped.
+    assert_eq!(
+        path.prepend(&NodePath::from("Level:ignored")),
+        Some(NodePath::from("Level/Player/Sprite:modulate"))
+    );
+
+    // Empty prefix or path.
+    assert_eq!(path.prepend(&NodePath::default()), Some(path.clone()));
+    assert_eq!(
+        NodePath::default().prepend(&NodePath::from("/root")),
+        Some(NodePath::from("/root"))
+    );
+
+    // Absolute paths cannot be prepended to.
+    assert_eq!(
+        NodePath::from("/root/Player").prepend(&NodePath::from("Level")),
+        None
+    );
+}
+
+#[itest]
+fn node_path_borrow_from_sys() {
+    use godot::sys::GodotFfi;
+
+    let path = NodePath::from("/root/Main:position");
+    let hash_before = path.hash();
+
+    // SAFETY: `path` is a live NodePath that outlives `borrowed` and is not modified meanwhile.
+    let borrowed = unsafe { NodePath::borrow_from_sys(path.sys()) };
+
+    assert_eq!(borrowed, &path);
+    assert_eq!(borrowed.to_string(), "/root/Main:position");
+    assert!(std::ptr::eq(borrowed, &path), "no copy is made");
+
+    // Borrowing does not affect the original (e.g. through a double release).
+    assert_eq!(path.hash(), hash_before);
+    assert_eq!(path.to_string(), "/root/Main:position");
+}
+
+#[itest]
+fn node_path_map_names() {
+    let prefix = |name: &StringName| StringName::from(format!("pre_{name}"));
+
+    assert_eq!(
+        NodePath::from("A/B").map_names(prefix),
+        NodePath::from("pre_A/pre_B")
+    );
+    assert_eq!(
+        NodePath::from("/root/Main:position:x").map_names(prefix),
+        NodePath::from("/pre_root/pre_Main:position:x")
+    );
+
+    // Identity.
+    let path = NodePath::from("/root/Main:position");
+    assert_eq!(path.map_names(|name| name.clone()), path);
+
+    // Empty path: nothing to map.
+    assert_eq!(NodePath::default().map_names(prefix), NodePath::default());
+}
+
+#[itest]
+fn node_path_starts_with() {
+    let path = NodePath::from("/root/Main/Player:position");
+
+    assert!(path.starts_with(&NodePath::from("/root/Main")));
+    assert!(path.starts_with(&NodePath::from("/root/Main/Player")));
+    assert!(path.starts_with(&NodePath::from("/root/Main/Player:scale")));
+    assert!(path.starts_with(&NodePath::from("/")));
+    assert!(path.starts_with(&path));
+
+    assert!(NodePath::from("A/B").starts_with(&NodePath::from("A")));
+    assert!(NodePath::from("A").starts_with(&NodePath::default()));
+}
+
+#[itest]
+fn node_path_starts_with_near_miss() {
+    let path = NodePath::from("/root/MainMenu/Button");
+
+    // A string comparison would report a match here.
+    assert!(path.to_string().starts_with("/root/Main"));
+    assert!(!path.starts_with(&NodePath::from("/root/Main")));
+
+    assert!(!NodePath::from("/root/Main").starts_with(&NodePath::from("/root/Main/Player")));
+}
+
+#[itest]
+fn node_path_starts_with_absoluteness() {
+    assert!(!NodePath::from("/root/Main").starts_with(&NodePath::from("root")));
+    assert!(!NodePath::from("root/Main").starts_with(&NodePath::from("/root")));
+    assert!(!NodePath::from("A").starts_with(&NodePath::from("/")));
+}
+
+#[itest]
+fn node_path_coerce_from_variant() {
+    let expected = NodePath::from("Level/Player:position");
+
+    let from_node_path = expected.to_variant();
+    assert_eq!(coerce_to_node_path(&from_node_path), Some(expected.clone()));
+
+    let from_string = GString::from("Level/Player:position").to_variant();
+    assert_eq!(coerce_to_node_path(&from_string), Some(expected.clone()));
+
+    let from_string_name = StringName::from("Level/Player:position").to_variant();
+    assert_eq!(coerce_to_node_path(&from_string_name), Some(expected));
+}
+
+#[itest]
+fn node_path_coerce_from_variant_rejected() {
+    assert_eq!(coerce_to_node_path(&42.to_variant()), None);
+    assert_eq!(coerce_to_node_path(&Variant::nil()), None);
+}
+
+#[itest]
+fn node_path_split_first_name() {
+    let (first, rest) = NodePath::from("A/B/C").split_first_name().unwrap();
+    assert_eq!(first, StringName::from("A"));
+    assert_eq!(rest, NodePath::from("B/C"));
+
+    // Remainder is relative and keeps the subnames.
+    let (first, rest) = NodePath::from("/root/Main:position:x")
+        .split_first_name()
+        .unwrap();
+    assert_eq!(first, StringName::from("root"));
+    assert_eq!(rest, NodePath::from("Main:position:x"));
+    assert!(!rest.as_inner().is_absolute());
+
+    // Peeling repeatedly visits every name.
+    let mut path = NodePath::from("A/B/C");
+    let mut visited = Vec::new();
+    while let Some((name, rest)) = path.split_first_name() {
+        visited.push(name.to_string());
+        path = rest;
+    }
+    assert_eq!(visited, ["A", "B", "C"]);
+    assert!(path.is_empty());
+}
+
+#[itest]
+fn node_path_split_first_name_single() {
+    let (first, rest) = NodePath::from("Player").split_first_name().unwrap();
+    assert_eq!(first, StringName::from("Player"));
+    assert!(rest.is_empty());
+
+    let (first, rest) = NodePath::from("Player:health").split_first_name().unwrap();
+    assert_eq!(first, StringName::from("Player"));
+    assert_eq!(rest, NodePath::from(":health"));
+
+    assert_eq!(NodePath::default().split_first_name(), None);
+    assert_eq!(NodePath::from(":health").split_first_name(), None);
+}
+
+#[itest]
+fn node_path_encode_decode() {
+    let paths = [
+        NodePath::from("/root/Level/Player:position:x"),
+        NodePath::from("Ünïcödé/節点"),
+        NodePath::default(),
+    ];
+
+    let mut buffer = Vec::new();
+    for path in &paths {
+        path.encode(&mut buffer).unwrap();
+    }
+
+    // Length prefix is a little-endian u32 byte count.
+    assert_eq!(&buffer[..4], &29u32.to_le_bytes());
+
+    let mut cursor = std::io::Cursor::new(buffer);
+    for path in &paths {
+        assert_eq!(&NodePath::decode(&mut cursor).unwrap(), path);
+    }
+
+    let err = NodePath::decode(&mut cursor).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+#[itest]
+fn node_path_gdscript_literal() {
+    let path = NodePath::from("/root/Main:position");
+    assert_eq!(path.to_gdscript_literal(), r#"^"/root/Main:position""#);
+    assert_eq!(
+        NodePath::from_gdscript_literal(&path.to_gdscript_literal()),
+        Ok(path)
+    );
+}
+
+#[itest]
+fn node_path_gdscript_literal_empty() {
+    let literal = NodePath::default().to_gdscript_literal();
+
+    assert_eq!(literal, r#"^"""#);
+    assert_eq!(
+        NodePath::from_gdscript_literal(&literal),
+        Ok(NodePath::default())
+    );
+}
+
+#[itest]
+fn node_path_gdscript_literal_escaped() {
+    let path = NodePath::from(r#"Say "hi"/Back\slash:text"#);
+    let literal = path.to_gdscript_literal();
+
+    assert_eq!(literal, r#"^"Say \"hi\"/Back\\slash:text""#);
+    assert_eq!(NodePath::from_gdscript_literal(&literal), Ok(path));
+
+    let path = NodePath::from("Line\nBreak\u{1}");
+    let literal = path.to_gdscript_literal();
+
+    assert_eq!(literal, r#"^"Line\nBreak\u0001""#);
+    assert_eq!(NodePath::from_gdscript_literal(&literal), Ok(path));
+}
+
+#[itest]
+fn node_path_gdscript_literal_invalid() {
+    use godot::meta::error::LiteralParseError;
+
+    let cases = [
+        (r#""A/B""#, LiteralParseError::MissingDelimiters),
+        (r#"^"A/B"#, LiteralParseError::MissingDelimiters),
+        (
+            r#"^"A"B""#,
+            LiteralParseError::UnescapedQuote { position: 3 },
+        ),
+        (
+            r#"^"A\qB""#,
+            LiteralParseError::InvalidEscape { position: 3 },
+        ),
+        (
+            r#"^"A\u12""#,
+            LiteralParseError::InvalidEscape { position: 3 },
+        ),
+        (r#"^"A\""#, LiteralParseError::InvalidEscape { position: 3 }),
+    ];
+
+    for (literal, expected) in cases {
+        assert_eq!(
+            NodePath::from_gdscript_literal(literal),
+            Err(expected),
+            "literal: {literal}"
+        );
+    }
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn node_path_resolve_cached(ctx: &TestContext) {
+    let tree = ctx.scene_tree.get_tree().unwrap();
+    let mut parent = ctx.scene_tree.clone();
+
+    let mut child = Node::new_alloc();
+    child.set_name("ResolveCachedChild".into());
+    parent.add_child(&child);
+
+    // Absolute and root-relative forms of the same path.
+    let path = child.get_path();
+    let relative = path.slice_names(1..);
+
+    assert_eq!(path.resolve_cached(&tree), Some(child.instance_id()));
+    assert_eq!(relative.resolve_cached(&tree), Some(child.instance_id()));
+
+    // Hit.
+    assert_eq!(path.resolve_cached(&tree), Some(child.instance_id()));
+
+    parent.remove_child(&child);
+    child.free();
+    NodePath::clear_resolve_cache();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn node_path_resolve_cached_stale(ctx: &TestContext) {
+    let tree = ctx.scene_tree.get_tree().unwrap();
+    let mut parent = ctx.scene_tree.clone();
+
+    let mut child = Node::new_alloc();
+    child.set_name("ResolveCachedStale".into());
+    parent.add_child(&child);
+
+    let path = child.get_path();
+    assert_eq!(path.resolve_cached(&tree), Some(child.instance_id()));
+
+    // Freed node: cached entry is invalidated, and the path no longer resolves.
+    parent.remove_child(&child);
+    child.free();
+    assert_eq!(path.resolve_cached(&tree), None);
+
+    // New node at the same path: resolved anew.
+    let mut replacement = Node::new_alloc();
+    replacement.set_name("ResolveCachedStale".into());
+    parent.add_child(&replacement);
+    assert_eq!(path.resolve_cached(&tree), Some(replacement.instance_id()));
+
+    // Renamed node: cached entry is stale, as the node is no longer at the path.
+    replacement.set_name("ResolveCachedRenamed".into());
+    assert_eq!(path.resolve_cached(&tree), None);
+
+    // Renamed ancestor: the paths of all descendants change.
+    let mut grandchild = Node::new_alloc();
+    grandchild.set_name("Grandchild".into());
+    replacement.add_child(&grandchild);
+
+    let path = grandchild.get_path();
+    assert_eq!(path.resolve_cached(&tree), Some(grandchild.instance_id()));
+
+    replacement.set_name("ResolveCachedRenamedAgain".into());
+    assert_eq!(path.resolve_cached(&tree), None);
+
+    // Moved node: cached entry is stale, as the node left the tree.
+    let path = grandchild.get_path();
+    assert_eq!(path.resolve_cached(&tree), Some(grandchild.instance_id()));
+
+    replacement.remove_child(&grandchild);
+    parent.add_child(&grandchild);
+    assert_eq!(path.resolve_cached(&tree), None);
+
+    parent.remove_child(&grandchild);
+    grandchild.free();
+    parent.remove_child(&replacement);
+    replacement.free();
+    NodePath::clear_resolve_cache();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn node_path_resolve_cached_miss(ctx: &TestContext) {
+    let tree = ctx.scene_tree.get_tree().unwrap();
+
+    assert_eq!(
+        NodePath::from("/root/DoesNotExist").resolve_cached(&tree),
+        None
+    );
+    assert_eq!(
+        NodePath::from("DoesNotExist/Child").resolve_cached(&tree),
+        None
+    );
+
+    NodePath::clear_resolve_cache();
+}
+
+#[itest]
+fn node_path_strict_builder() {
+    let mut builder = StrictNodePathBuilder::new();
+    assert_eq!(builder.push_name("a"), Ok(()));
+    assert_eq!(builder.push_name("b"), Ok(()));
+    assert_eq!(builder.push_subname("prop"), Ok(()));
+
+    let path = builder.build();
+    assert_eq!(path, NodePath::from("a/b:prop"));
+    assert_eq!(path.as_inner().get_name_count(), 2);
+
+    let mut builder = StrictNodePathBuilder::new_absolute();
+    builder.push_name("root").unwrap();
+    assert_eq!(builder.build(), NodePath::from("/root"));
+
+    assert_eq!(StrictNodePathBuilder::new().build(), NodePath::default());
+}
+
+#[itest]
+fn node_path_strict_builder_rejects() {
+    let mut builder = StrictNodePathBuilder::new();
+
+    assert_eq!(
+        builder.push_name("a/b"),
+        Err(ComponentError::IllegalCharacter {
+            component: "a/b".to_string(),
+            character: '/',
+        })
+    );
+    assert_eq!(
+        builder.push_subname("x:y"),
+        Err(ComponentError::IllegalCharacter {
+            component: "x:y".to_string(),
+            character: ':',
+        })
+    );
+    assert!(builder.push_name("nul\0byte").is_err());
+    assert_eq!(builder.push_name(""), Err(ComponentError::Empty));
+
+    // Rejected components are not added.
+    builder.push_name("a").unwrap();
+    assert_eq!(builder.build(), NodePath::from("a"));
+}
+
+#[itest]
+fn node_path_decode_invalid() {
+    // Length announces more bytes than available.
+    let mut truncated = std::io::Cursor::new([5, 0, 0, 0, b'A', b'B']);
+    let err = NodePath::decode(&mut truncated).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+    let mut invalid_utf8 = std::io::Cursor::new([2, 0, 0, 0, 0xC3, 0x28]);
+    let err = NodePath::decode(&mut invalid_utf8).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[itest]
+fn node_path_contains_name() {
+    let path = NodePath::from("/root/UI/HealthBar:modulate:a");
+
+    assert!(path.contains_name(&StringName::from("root")));
+    assert!(path.contains_name(&StringName::from("UI")));
+    assert!(path.contains_name(&StringName::from("HealthBar")));
+
+    // Only whole names match.
+    assert!(!path.contains_name(&StringName::from("Health")));
+    assert!(!path.contains_name(&StringName::from("U")));
+
+    // Subnames are not names.
+    assert!(!path.contains_name(&StringName::from("modulate")));
+
+    assert!(!NodePath::default().contains_name(&StringName::from("UI")));
+}
+
+#[itest]
+fn node_path_contains_subname() {
+    let path = NodePath::from("/root/UI/HealthBar:modulate:a");
+
+    assert!(path.contains_subname(&StringName::from("modulate")));
+    assert!(path.contains_subname(&StringName::from("a")));
+
+    assert!(!path.contains_subname(&StringName::from("mod")));
+    assert!(!path.contains_subname(&StringName::from("UI")));
+}
+
+#[itest]
+fn node_path_longest_prefix_in() {
+    let candidates = [
+        NodePath::from("A"),
+        NodePath::from("A/B"),
+        NodePath::from("A/BC"),
+        NodePath::from("/A/B/C"),
+    ];
+
+    let path = NodePath::from("A/B/C");
+    assert_eq!(path.longest_prefix_in(&candidates), Some(&candidates[1]));
+
+    // Only whole names count, so "A/BC" is not a prefix of "A/B".
+    let path = NodePath::from("A/BCD");
+    assert_eq!(path.longest_prefix_in(&candidates), Some(&candidates[0]));
+
+    // Absolute candidate only matches absolute paths.
+    let path = NodePath::from("/A/B/C/D");
+    assert_eq!(path.longest_prefix_in(&candidates), Some(&candidates[3]));
+
+    assert_eq!(NodePath::from("X").longest_prefix_in(&candidates), None);
+    assert_eq!(path.longest_prefix_in(&[]), None);
+}
+
+#[itest]
+fn node_path_longest_prefix_in_tie() {
+    // Same names, different subnames: the first candidate wins.
+    let candidates = [NodePath::from("A:x"), NodePath::from("A:y")];
+
+    let path = NodePath::from("A/B");
+    let found = path.longest_prefix_in(&candidates).unwrap();
+    assert!(std::ptr::eq(found, &candidates[0]));
+}
+
+#[itest]
+fn node_path_elide_middle_unchanged() {
+    let path = NodePath::from("/root/Level/Player:position");
+    assert_eq!(path.elide_middle(100), "/root/Level/Player:position");
+    assert_eq!(path.elide_middle(27), "/root/Level/Player:position");
+
+    // Too few names to elide anything.
+    let path = NodePath::from("VeryLongFirstName/VeryLongLastName");
+    assert_eq!(path.elide_middle(5), "VeryLongFirstName/VeryLongLastName");
+}
+
+#[itest]
+fn node_path_elide_middle() {
+    let path = NodePath::from("/root/Level/World/Enemies/Goblin");
+
+    assert_eq!(path.elide_middle(31), "/root/.../World/Enemies/Goblin");
+    assert_eq!(path.elide_middle(24), "/root/.../Enemies/Goblin");
+    assert_eq!(path.elide_middle(23), "/root/.../Goblin");
+
+    // First and last names are kept even if the result exceeds the limit.
+    assert_eq!(path.elide_middle(3), "/root/.../Goblin");
+
+    // Subnames stay attached to the last name.
+    let path = NodePath::from("A/B/C/D/E:modulate:a");
+    assert_eq!(path.elide_middle(19), "A/.../E:modulate:a");
+    assert_eq!(path.elide_middle(20), "A/B/C/D/E:modulate:a");
+}
+
+#[itest]
+fn node_path_elide_middle_not_shorter() {
+    // "A/.../C" would be longer than the original.
+    let path = NodePath::from("A/B/C");
+    assert_eq!(path.elide_middle(4), "A/B/C");
+
+    // Same length is not worth eliding either.
+    let path = NodePath::from("A/BCD/E");
+    assert_eq!(path.elide_middle(6), "A/BCD/E");
+}
+
+#[itest]
+fn node_path_to_from_bytes() {
+    let path = NodePath::from("/root/Nöde:position");
+
+    let bytes = path.to_bytes();
+    assert_eq!(bytes[0], 1, "version tag");
+    assert_eq!(&bytes[1..5], &20u32.to_le_bytes());
+    assert_eq!(&bytes[5..], "/root/Nöde:position".as_bytes());
+
+    assert_eq!(NodePath::from_bytes(&bytes), Ok(path));
+
+    let empty = NodePath::default();
+    assert_eq!(empty.to_bytes(), [1, 0, 0, 0, 0]);
+    assert_eq!(NodePath::from_bytes(&empty.to_bytes()), Ok(empty));
+}
+
+#[itest]
+fn node_path_from_bytes_malformed() {
+    assert_eq!(
+        NodePath::from_bytes(&[2, 0, 0, 0, 0]),
+        Err(NodePathDecodeError::UnsupportedVersion(2))
+    );
+
+    assert_eq!(
+        NodePath::from_bytes(&[]),
+        Err(NodePathDecodeError::TruncatedLength)
+    );
+    assert_eq!(
+        NodePath::from_bytes(&[1, 3, 0]),
+        Err(NodePathDecodeError::TruncatedLength)
+    );
+    assert_eq!(
+        NodePath::from_bytes(&[1, 3, 0, 0, 0, b'A', b'B']),
+        Err(NodePathDecodeError::TruncatedData(1))
+    );
+
+    assert_eq!(
+        NodePath::from_bytes(&[1, 2, 0, 0, 0, 0xC3, 0x28]),
+        Err(NodePathDecodeError::InvalidUtf8)
+    );
+
+    assert_eq!(
+        NodePath::from_bytes(&[1, 1, 0, 0, 0, b'A', b'B', b'C']),
+        Err(NodePathDecodeError::TrailingBytes(2))
+    );
+}
+
+#[itest]
+fn node_path_retain_names() {
+    let container = StringName::from("Container");
+    let path = NodePath::from("A/Container/B");
+
+    assert_eq!(
+        path.retain_names(|name| name != &container),
+        NodePath::from("A/B")
+    );
+
+    // Absolute flag and subnames are kept.
+    let path = NodePath::from("/root/Container/UI/Container/Bar:value:x");
+    assert_eq!(
+        path.retain_names(|name| name != &container),
+        NodePath::from("/root/UI/Bar:value:x")
+    );
+}
+
+#[itest]
+fn node_path_retain_names_all_or_nothing() {
+    let path = NodePath::from("A/B:prop");
+
+    assert_eq!(path.retain_names(|_| true), path);
+    assert_eq!(path.retain_names(|_| false), NodePath::from(":prop"));
+
+    let path = NodePath::from("A/B");
+    assert!(path.retain_names(|_| false).is_empty());
+}
+
+#[itest]
+fn node_path_hash_into() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let composite_key = |path: &NodePath, entity_id: u64| {
+        let mut hasher = DefaultHasher::new();
+        path.hash_into(&mut hasher);
+        entity_id.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    let a = NodePath::from("/root/World/Goblin");
+    let b = NodePath::from(String::from("/root/World/Goblin"));
+    assert_eq!(composite_key(&a, 7), composite_key(&b, 7));
+    assert_ne!(composite_key(&a, 7), composite_key(&a, 8));
+    assert_ne!(
+        composite_key(&a, 7),
+        composite_key(&NodePath::from("/root/World/Orc"), 7)
+    );
+
+    // Same state as the `Hash` impl.
+    let mut via_hash_into = DefaultHasher::new();
+    a.hash_into(&mut via_hash_into);
+
+    let mut via_hash = DefaultHasher::new();
+    Hash::hash(&a, &mut via_hash);
+    assert_eq!(via_hash_into.finish(), via_hash.finish());
+}
+
+#[itest]
+fn node_path_is_strict_identifier_path() {
+    assert!(NodePath::from("Player/Sprite2D:modulate:a").is_strict_identifier_path());
+    assert!(NodePath::from("/root/_private/Node_1").is_strict_identifier_path());
+    assert!(NodePath::from(":health").is_strict_identifier_path());
+
+    // Accepted by Godot, rejected by the strict check.
+    assert!(!NodePath::from("Player/Health Bar").is_strict_identifier_path());
+    assert!(!NodePath::from("Player:my prop").is_strict_identifier_path());
+    assert!(!NodePath::from("Level/2ndFloor").is_strict_identifier_path());
+    assert!(!NodePath::from("Player:0").is_strict_identifier_path());
+    assert!(!NodePath::from("../Sibling").is_strict_identifier_path());
+
+    assert!(!NodePath::default().is_strict_identifier_path());
+}
+
+#[itest]
+fn node_path_diff() {
+    let name = |s: &str| Some(StringName::from(s));
+
+    let a = NodePath::from("/root/Level/Enemies/Goblin:position");
+    let b = NodePath::from("/root/Level/Items");
+
+    let pairs: Vec<_> = a.diff(&b).collect();
+    assert_eq!(
+        pairs,
+        [
+            (name("root"), name("root")),
+            (name("Level"), name("Level")),
+            (name("Enemies"), name("Items")),
+            (name("Goblin"), None),
+        ]
+    );
+
+    // Reversed: the other side runs out.
+    let pairs: Vec<_> = b.diff(&a).collect();
+    assert_eq!(pairs[3], (None, name("Goblin")));
+
+    assert_eq!(a.diff(&b).position(|(a, b)| a != b), Some(2));
+    assert_eq!(a.diff(&a).position(|(a, b)| a != b), None);
+    assert_eq!(NodePath::default().diff(&NodePath::default()).count(), 0);
+}
+
+#[itest]
+fn node_path_cmp_natural() {
+    use std::cmp::Ordering;
+
+    let cmp = |a: &str, b: &str| NodePath::from(a).cmp_natural(&NodePath::from(b));
+
+    assert_eq!(cmp("Item2", "Item10"), Ordering::Less);
+    assert_eq!(cmp("Item10", "Item2"), Ordering::Greater);
+    assert_eq!(cmp("Item10", "Item10"), Ordering::Equal);
+    assert_eq!(
+        cmp("/root/Level9/Spawn", "/root/Level10/Spawn"),
+        Ordering::Less
+    );
+    assert_eq!(cmp("Node:frame_2", "Node:frame_11"), Ordering::Less);
+
+    // Numbers beyond u64 range.
+    assert_eq!(
+        cmp("Id99999999999999999999", "Id100000000000000000000"),
+        Ordering::Less
+    );
+
+    // Text runs are compared case-sensitively, before any later numbers.
+    assert_eq!(cmp("A10", "B2"), Ordering::Less);
+
+    // Leading zeros only break ties.
+    assert_eq!(cmp("Item01", "Item1"), Ordering::Less);
+    assert_eq!(cmp("Item01", "Item2"), Ordering::Less);
+}
+
+#[itest]
+fn node_path_sort_natural() {
+    let mut paths: Vec<NodePath> = ["Slot10", "Slot1", "Slot2/Item3", "Slot2/Item20", "Slot2"]
+        .into_iter()
+        .map(NodePath::from)
+        .collect();
+
+    paths.sort_by(NodePath::cmp_natural);
+
+    let expected: Vec<NodePath> = ["Slot1", "Slot2", "Slot2/Item3", "Slot2/Item20", "Slot10"]
+        .into_iter()
+        .map(NodePath::from)
+        .collect();
+    assert_eq!(paths, expected);
+}
+
+#[itest]
+fn node_path_hash_bytes() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    let hash_bytes = |path: &NodePath| {
+        let mut hasher = DefaultHasher::new();
+        path.hash_bytes(&mut hasher);
+        hasher.finish()
+    };
+
+    let a = NodePath::from("/root/World/Goblin");
+    assert_eq!(
+        hash_bytes(&a),
+        hash_bytes(&NodePath::from("/root/World/Goblin"))
+    );
+    assert_ne!(
+        hash_bytes(&a),
+        hash_bytes(&NodePath::from("/root/World/Goblim"))
+    );
+
+    // Many similar paths, all hashes distinct and spread over the whole 64-bit range.
+    let hashes: HashSet<u64> = (0..5000)
+        .map(|i| hash_bytes(&NodePath::from(format!("/root/Level/Enemy{i}:position"))))
+        .collect();
+    assert_eq!(hashes.len(), 5000);
+
+    let high_bits_used = hashes.iter().filter(|&&hash| hash >> 32 != 0).count();
+    assert!(high_bits_used > 4900, "upper 32 bits should be used");
+}
+
+#[itest]
+fn node_path_accept_visitor() {
+    // Renders names joined by " > " and subnames joined by ".", e.g. for a breadcrumb display.
+    #[derive(Default)]
+    struct Breadcrumbs {
+        text: String,
+        visited: Vec<(bool, usize)>,
+    }
+
+    impl NodePathVisitor for Breadcrumbs {
+        fn visit_name(&mut self, index: usize, name: &StringName) {
+            if index > 0 {
+                self.text.push_str(" > ");
+            }
+            self.text.push_str(&name.to_string());
+            self.visited.push((true, index));
+        }
+
+        fn visit_subname(&mut self, index: usize, subname: &StringName) {
+            self.text.push_str(if index == 0 { " @ " } else { "." });
+            self.text.push_str(&subname.to_string());
+            self.visited.push((false, index));
+        }
+    }
+
+    let mut visitor = Breadcrumbs::default();
+    NodePath::from("/root/Main/Player:position:x").accept(&mut visitor);
+
+    assert_eq!(visitor.text, "root > Main > Player @ position.x");
+    assert_eq!(
+        visitor.visited,
+        [(true, 0), (true, 1), (true, 2), (false, 0), (false, 1)]
+    );
+
+    let mut visitor = Breadcrumbs::default();
+    NodePath::default().accept(&mut visitor);
+    assert!(visitor.text.is_empty());
+}
+
+#[itest]
+fn node_path_components_snapshot() {
+    let path = NodePath::from("/root/Level/Player:position:x");
+    let components = path.components();
+
+    let live_names: Vec<StringName> = path
+        .iter_components()
+        .filter_map(|component| match component {
+            NodePathComponent::Name(name) => Some(name),
+            NodePathComponent::Subname(_) => None,
+        })
+        .collect();
+
+    assert_eq!(components.names(), live_names.as_slice());
+    assert_eq!(components.names()[2], StringName::from("Player"));
+    assert_eq!(
+        components.subnames(),
+        [StringName::from("position"), StringName::from("x")]
+    );
+    assert!(components.is_absolute());
+
+    assert!(components.iter().eq(path.iter_components()));
+    assert_eq!(components.to_node_path(), path);
+
+    let empty = NodePath::default().components();
+    assert!(empty.names().is_empty());
+    assert!(empty.subnames().is_empty());
+    assert!(!empty.is_absolute());
+}
+
+#[itest]
+fn node_path_from_parts() {
+    let names = [StringName::from("A"), StringName::from("B")];
+    let subnames = [StringName::from("position"), StringName::from("x")];
+
+    let names_only = NodePath::from_parts(&names, &[], false);
+    assert_eq!(names_only, NodePath::from("A/B"));
+    assert_eq!(names_only.components().names(), names);
+    assert!(names_only.components().subnames().is_empty());
+
+    let subnames_only = NodePath::from_parts(&[], &subnames, false);
+    assert_eq!(subnames_only, NodePath::from(":position:x"));
+    assert!(subnames_only.components().names().is_empty());
+    assert_eq!(subnames_only.components().subnames(), subnames);
+
+    let mixed = NodePath::from_parts(&names, &subnames, true);
+    assert_eq!(mixed, NodePath::from("/A/B:position:x"));
+    assert!(mixed.as_inner().is_absolute());
+    assert_eq!(mixed.components().names(), names);
+    assert_eq!(mixed.components().subnames(), subnames);
+
+    let root = NodePath::from_parts(&[], &[], true);
+    assert_eq!(root.to_string(), "/");
+    assert!(root.as_inner().is_absolute());
+
+    assert!(NodePath::from_parts(&[], &[], false).is_empty());
+}
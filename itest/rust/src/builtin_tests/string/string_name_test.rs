@@ -7,7 +7,8 @@
 
 use std::collections::HashSet;
 
-use crate::framework::{assert_eq_self, itest};
+use crate::framework::{assert_eq_self, expect_panic, itest};
+use godot::builtin::strings::StringNameMap;
 use godot::builtin::{GString, NodePath, StringName};
 
 #[itest]
@@ -144,6 +145,27 @@ fn string_name_from_cstr() {
     }
 }
 
+#[itest]
+fn string_name_from_ascii() {
+    let cases = ["", "pure ASCII\t[~]", "_ready", "on_body_entered"];
+
+    for string in cases {
+        let ascii = StringName::from_ascii(string);
+
+        assert_eq!(ascii, StringName::from(string));
+        assert_eq!(ascii.to_string(), string);
+        assert_eq!(ascii.hash(), StringName::from(string).hash());
+    }
+}
+
+#[itest]
+#[cfg(debug_assertions)]
+fn string_name_from_ascii_non_ascii() {
+    expect_panic("non-ASCII input", || {
+        StringName::from_ascii("café");
+    });
+}
+
 #[itest]
 fn string_name_with_null() {
     // Godot always ignores bytes after a null byte.
@@ -162,3 +184,35 @@ fn string_name_with_null() {
         assert_eq!(left, right);
     }
 }
+
+#[itest]
+fn string_name_map() {
+    let mut map = StringNameMap::new();
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert(StringName::from("jump"), 1), None);
+    assert_eq!(map.insert(StringName::from("run"), 2), None);
+    assert_eq!(map.insert(StringName::from("attack"), 3), None);
+    assert_eq!(map.insert(StringName::from("run"), 20), Some(2));
+    assert_eq!(map.len(), 3);
+
+    assert_eq!(map.get(&StringName::from("jump")), Some(&1));
+    assert_eq!(map.get(&StringName::from("run")), Some(&20));
+    assert_eq!(map.get(&StringName::from("attack")), Some(&3));
+    assert_eq!(map.get(&StringName::from("Jump")), None);
+    assert_eq!(map.get(&StringName::from("missing")), None);
+
+    *map.get_mut(&StringName::from("jump")).unwrap() += 10;
+    assert_eq!(map.remove(&StringName::from("attack")), Some(3));
+    assert!(!map.contains_key(&StringName::from("attack")));
+
+    let mut entries: Vec<(String, i32)> = map
+        .iter()
+        .map(|(key, &value)| (key.to_string(), value))
+        .collect();
+    entries.sort();
+    assert_eq!(
+        entries,
+        vec![("jump".to_string(), 11), ("run".to_string(), 20)]
+    );
+}
@@ -10,7 +10,7 @@ use std::rc::Rc;
 
 use godot::builtin::{GString, StringName, Variant, Vector3};
 use godot::classes::{
-    file_access, Area2D, Camera3D, Engine, FileAccess, IRefCounted, Node, Node3D, Object,
+    file_access, Area2D, Camera3D, Engine, FileAccess, IRefCounted, Node, Node2D, Node3D, Object,
     RefCounted,
 };
 use godot::global::instance_from_id;
@@ -206,6 +206,27 @@ fn object_from_instance_id_inherits_type() {
     node_as_base.free();
 }
 
+#[itest]
+fn object_try_from_instance_id_valid() {
+    let node: Gd<Node3D> = Node3D::new_alloc();
+    let id = node.instance_id();
+
+    let same = Gd::<Node3D>::try_from_instance_id(id).expect("object is alive");
+    assert_eq!(same, node);
+
+    node.free();
+}
+
+#[itest]
+fn object_try_from_instance_id_freed() {
+    let node: Gd<Node3D> = Node3D::new_alloc();
+    let id = node.instance_id();
+    node.free();
+
+    Gd::<Node3D>::try_from_instance_id(id)
+        .expect_err("try_from_instance_id() on freed object should fail");
+}
+
 #[itest]
 fn object_from_instance_id_unrelated_type() {
     let node: Gd<Node3D> = Node3D::new_alloc();
@@ -648,6 +669,36 @@ fn object_engine_bad_downcast() {
     object2.free();
 }
 
+#[itest]
+fn object_engine_try_cast_node_subtype() {
+    let node: Gd<Node> = Node3D::new_alloc().upcast();
+    let id = node.instance_id();
+
+    // Wrong subtype: original object is handed back.
+    let node: Gd<Node> = node
+        .try_cast::<Node2D>()
+        .expect_err("Node3D must not be castable to Node2D");
+    assert_eq!(node.instance_id(), id);
+
+    // Correct subtype.
+    let node3d: Gd<Node3D> = node.try_cast::<Node3D>().expect("try_cast");
+    assert_eq!(node3d.instance_id(), id);
+
+    node3d.free();
+}
+
+#[itest]
+fn object_engine_try_cast_dead_instance() {
+    let node: Gd<Node> = Node3D::new_alloc().upcast();
+    let copy = node.clone();
+    node.free();
+
+    let copy = copy
+        .try_cast::<Node3D>()
+        .expect_err("dead instance must not be castable");
+    assert!(!copy.is_instance_valid());
+}
+
 #[itest]
 fn object_engine_accept_polymorphic() {
     let mut node = Camera3D::new_alloc();
@@ -1065,3 +1116,36 @@ fn double_use_reference() {
 #[derive(GodotClass)]
 #[class(no_init, base = EditorPlugin, editor_plugin, tool)]
 struct CustomEditorPlugin;
+
+#[itest]
+fn object_weak_upgrade_manual() {
+    let node: Gd<Node3D> = Node3D::new_alloc();
+    let weak = node.downgrade();
+
+    assert_eq!(weak.instance_id(), node.instance_id());
+    assert_eq!(weak.upgrade(), Some(node.clone()));
+    assert!(weak.is_alive());
+
+    node.free();
+
+    assert_eq!(weak.upgrade(), None);
+    assert!(!weak.is_alive());
+}
+
+#[itest]
+fn object_weak_upgrade_refcounted() {
+    let obj: Gd<RefCounted> = RefCounted::new_gd();
+    let weak = obj.downgrade();
+
+    // Weak pointer does not contribute to the reference count.
+    assert_eq!(obj.get_reference_count(), 1);
+
+    let strong = weak.upgrade().expect("object is alive");
+    assert_eq!(strong, obj);
+    assert_eq!(obj.get_reference_count(), 2);
+
+    drop(strong);
+    drop(obj);
+
+    assert_eq!(weak.upgrade(), None);
+}